@@ -0,0 +1,290 @@
+//! Genome Distance & Alignment
+//!
+//! Hamming distance on the flat sequence, a symmetry-invariant distance (the
+//! minimum Hamming distance over the cube group, so rotated copies of the
+//! same cube are distance 0), and Needleman-Wunsch / Smith-Waterman
+//! alignment of the 27-base strings.
+
+use anyhow::Result;
+use serde::{Serialize, Deserialize};
+
+use crate::database::DivineDatabase;
+use crate::genome::{Tetrad, GENOME_SIZE};
+use crate::symmetry::CubeSymmetry;
+
+/// Number of differing tetrads
+pub fn hamming_distance(a: &str, b: &str) -> u32 {
+    let diff = a.bytes().zip(b.bytes()).filter(|(x, y)| x != y).count();
+    (diff + a.len().abs_diff(b.len())) as u32
+}
+
+pub fn hamming(a: &[Tetrad; GENOME_SIZE], b: &[Tetrad; GENOME_SIZE]) -> u32 {
+    a.iter().zip(b.iter()).filter(|(x, y)| x != y).count() as u32
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DistanceMetric {
+    /// Position-by-position on the flat sequence
+    #[default]
+    Hamming,
+    /// Minimum over the 24 cube rotations
+    Rotation,
+    /// Minimum over all 48 cube symmetries (rotations and reflections)
+    Symmetry,
+}
+
+impl DistanceMetric {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "hamming" => Some(Self::Hamming),
+            "rotation" | "rotational" => Some(Self::Rotation),
+            "symmetry" | "full" => Some(Self::Symmetry),
+            _ => None,
+        }
+    }
+
+    /// Group the distance is minimised over
+    pub fn group(&self) -> Vec<CubeSymmetry> {
+        match self {
+            Self::Hamming => vec![CubeSymmetry::IDENTITY],
+            Self::Rotation => CubeSymmetry::rotations(),
+            Self::Symmetry => CubeSymmetry::all(),
+        }
+    }
+
+    /// Every image of `data` under the metric's group, as DNA strings
+    pub fn variants(&self, data: &[Tetrad; GENOME_SIZE]) -> Vec<String> {
+        let mut variants: Vec<String> = self.group().iter()
+            .map(|g| g.apply(data).iter().map(|t| t.to_char()).collect())
+            .collect();
+        variants.sort();
+        variants.dedup();
+        variants
+    }
+}
+
+/// Distance and the symmetry of `b` that achieves it
+pub fn invariant_distance(
+    a: &[Tetrad; GENOME_SIZE],
+    b: &[Tetrad; GENOME_SIZE],
+    metric: DistanceMetric,
+) -> (u32, CubeSymmetry) {
+    metric.group().into_iter()
+        .map(|g| (hamming(a, &g.apply(b)), g))
+        .min_by_key(|(d, _)| *d)
+        .unwrap_or((hamming(a, b), CubeSymmetry::IDENTITY))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlignMode {
+    /// Needleman-Wunsch, end to end
+    #[default]
+    Global,
+    /// Smith-Waterman, best-scoring local segment
+    Local,
+}
+
+impl AlignMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "global" | "nw" | "needleman-wunsch" => Some(Self::Global),
+            "local" | "sw" | "smith-waterman" => Some(Self::Local),
+            _ => None,
+        }
+    }
+}
+
+/// Linear gap scoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlignmentScoring {
+    pub match_score: i32,
+    pub mismatch: i32,
+    pub gap: i32,
+}
+
+impl Default for AlignmentScoring {
+    fn default() -> Self {
+        Self { match_score: 2, mismatch: -1, gap: -2 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alignment {
+    pub mode: AlignMode,
+    pub score: i32,
+    /// Aligned rows, `-` for gaps
+    pub aligned_a: String,
+    pub aligned_b: String,
+    /// `|` match, `.` mismatch, ` ` gap
+    pub markup: String,
+    /// Aligned span in each input, half-open
+    pub start_a: usize,
+    pub end_a: usize,
+    pub start_b: usize,
+    pub end_b: usize,
+    pub matches: usize,
+    pub mismatches: usize,
+    pub gaps: usize,
+}
+
+impl Alignment {
+    /// Matches over aligned columns
+    pub fn identity(&self) -> f64 {
+        let columns = self.matches + self.mismatches + self.gaps;
+        if columns == 0 { 0.0 } else { self.matches as f64 / columns as f64 }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Step {
+    Stop,
+    Diagonal,
+    Up,
+    Left,
+}
+
+pub fn align(a: &str, b: &str, mode: AlignMode, scoring: &AlignmentScoring) -> Alignment {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+    let local = mode == AlignMode::Local;
+
+    let mut score = vec![vec![0i32; m + 1]; n + 1];
+    let mut trace = vec![vec![Step::Stop; m + 1]; n + 1];
+    if !local {
+        for i in 1..=n {
+            score[i][0] = i as i32 * scoring.gap;
+            trace[i][0] = Step::Up;
+        }
+        for j in 1..=m {
+            score[0][j] = j as i32 * scoring.gap;
+            trace[0][j] = Step::Left;
+        }
+    }
+
+    let mut best = (0, n, m);
+    for i in 1..=n {
+        for j in 1..=m {
+            let sub = if a[i - 1] == b[j - 1] { scoring.match_score } else { scoring.mismatch };
+            let mut cell = (score[i - 1][j - 1] + sub, Step::Diagonal);
+            if score[i - 1][j] + scoring.gap > cell.0 {
+                cell = (score[i - 1][j] + scoring.gap, Step::Up);
+            }
+            if score[i][j - 1] + scoring.gap > cell.0 {
+                cell = (score[i][j - 1] + scoring.gap, Step::Left);
+            }
+            if local && cell.0 <= 0 {
+                cell = (0, Step::Stop);
+            }
+            (score[i][j], trace[i][j]) = cell;
+            if local && cell.0 > best.0 {
+                best = (cell.0, i, j);
+            }
+        }
+    }
+    let (total, end_a, end_b) = if local { best } else { (score[n][m], n, m) };
+
+    let (mut i, mut j) = (end_a, end_b);
+    let (mut row_a, mut row_b, mut markup) = (Vec::new(), Vec::new(), Vec::new());
+    let (mut matches, mut mismatches, mut gaps) = (0, 0, 0);
+    loop {
+        match trace[i][j] {
+            Step::Stop => break,
+            Step::Diagonal => {
+                i -= 1;
+                j -= 1;
+                row_a.push(a[i]);
+                row_b.push(b[j]);
+                if a[i] == b[j] {
+                    matches += 1;
+                    markup.push('|');
+                } else {
+                    mismatches += 1;
+                    markup.push('.');
+                }
+            }
+            Step::Up => {
+                i -= 1;
+                row_a.push(a[i]);
+                row_b.push('-');
+                markup.push(' ');
+                gaps += 1;
+            }
+            Step::Left => {
+                j -= 1;
+                row_a.push('-');
+                row_b.push(b[j]);
+                markup.push(' ');
+                gaps += 1;
+            }
+        }
+        if local && score[i][j] == 0 {
+            break;
+        }
+    }
+
+    Alignment {
+        mode,
+        score: total,
+        aligned_a: row_a.iter().rev().collect(),
+        aligned_b: row_b.iter().rev().collect(),
+        markup: markup.iter().rev().collect(),
+        start_a: i,
+        end_a,
+        start_b: j,
+        end_b,
+        matches,
+        mismatches,
+        gaps,
+    }
+}
+
+/// All pairwise measures between two genomes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenomeComparison {
+    pub hamming: u32,
+    pub rotation_distance: u32,
+    /// Rotation of the second genome achieving `rotation_distance`
+    pub rotation: String,
+    pub symmetry_distance: u32,
+    pub symmetry: String,
+    pub alignment: Alignment,
+}
+
+impl GenomeComparison {
+    pub fn of(a: &[Tetrad; GENOME_SIZE], b: &[Tetrad; GENOME_SIZE], mode: AlignMode) -> Self {
+        let dna = |d: &[Tetrad; GENOME_SIZE]| d.iter().map(|t| t.to_char()).collect::<String>();
+        let (rotation_distance, rotation) = invariant_distance(a, b, DistanceMetric::Rotation);
+        let (symmetry_distance, symmetry) = invariant_distance(a, b, DistanceMetric::Symmetry);
+        Self {
+            hamming: hamming(a, b),
+            rotation_distance,
+            rotation: rotation.name(),
+            symmetry_distance,
+            symmetry: symmetry.name(),
+            alignment: align(&dna(a), &dna(b), mode, &AlignmentScoring::default()),
+        }
+    }
+}
+
+/// One result of a nearest-neighbour search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Neighbor {
+    pub id: i64,
+    pub dna: String,
+    pub distance: u32,
+    pub consciousness: u32,
+}
+
+/// The `limit` stored genomes closest to `data` under `metric`
+pub async fn nearest(
+    db: &DivineDatabase,
+    data: &[Tetrad; GENOME_SIZE],
+    metric: DistanceMetric,
+    limit: i64,
+    exclude: Option<i64>,
+) -> Result<Vec<Neighbor>> {
+    db.nearest_genomes(&metric.variants(data), limit.max(1), exclude).await
+}
@@ -8,18 +8,39 @@ use tokio::sync::RwLock;
 use axum::{
     routing::{get, post},
     Router, Json,
+    body::Bytes,
     extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
 };
 use serde::{Deserialize, Serialize};
 use tower_http::cors::CorsLayer;
 use tracing::info;
 
+use crate::codon::{CodonTable, Phenotype};
 use crate::database::DivineDatabase;
-use crate::genome::{Genome, GenomeBuilder, Tetrad};
+use crate::rna::Transcript;
+use crate::sequencing::{Sequencer, SequencingRun};
+use crate::damage::SurvivalReport;
+use crate::lifecycle::{self, LifecycleState, LifecycleTransition};
+use crate::diploid::{Dominance, Homologs};
+use crate::alignment::{self, AlignMode, DistanceMetric, GenomeComparison, Neighbor};
+use crate::consciousness::{ConsciousnessReport, ConsciousnessVersion};
+use crate::cube::Coord;
+use crate::genome::{Genome, GenomeBuilder, Tetrad, GENOME_SIZE};
 use crate::rotation::{Rot180, RotationEngine, RotationStats};
-use crate::ttrl::{TTRLEngine, EvolutionResult};
+use crate::ttrl::{TTRLEngine, EvolutionResult, MutationOperator, PopulationConfig, GenerationStats};
 use crate::exchange::{RSMExchange, ExchangeStats, Transaction, BurnEvent, DebtStats, OwnerPoolStats, BurnReason};
+use crate::operator_selector::OperatorSelectorStats;
 use crate::multi_chain::{MultiChainArchiver, ChainArchiveEntry, MissionControlStats};
+use crate::motif::{self, MotifMatch, MotifPattern};
+use crate::lineage::{CrisprEdit, Lineage, Origin, DEFAULT_LINEAGE_DEPTH};
+use crate::phylogeny::{self, PhyloTree, TreeMethod};
+use crate::rng::RngSource;
+use crate::landscape::{LandscapeAnalysis, LandscapeConfig};
+use crate::scanner::{self, ScanReport, SequenceScanner};
+use crate::solver::{DesignConstraints, DesignResult, DesignTarget, InverseSolver, SolverStrategy};
+use crate::symmetry::{self, CubeSymmetry, SymmetryProfile};
 use crate::auth::{AuthManager, WalletAccount, LoginRequest, RegisterRequest, LoginResponse, WalletInfo};
 
 #[derive(Clone)]
@@ -30,6 +51,7 @@ pub struct AppState {
     pub exchange: Arc<RwLock<RSMExchange>>,
     pub archiver: Arc<RwLock<MultiChainArchiver>>,
     pub auth: Arc<RwLock<AuthManager>>,
+    pub rng: RngSource,
 }
 
 #[derive(Serialize)]
@@ -53,6 +75,8 @@ pub struct GenomeResponse {
     pub id: i64,
     pub dna: String,
     pub consciousness: u32,
+    pub consciousness_model: String,
+    pub consciousness_level: String,
     pub mutations: u64,
     pub p53_copies: u8,
     pub telomere_length: u16,
@@ -63,6 +87,20 @@ pub struct GenomeResponse {
     pub tg_ratio: f64,
     pub suggested_rotation: String,
     pub mode: String,
+    pub parent_ids: Vec<i64>,
+    pub generation: u32,
+    pub origin: Origin,
+    /// Maternal / paternal strands of a diploid genome (`dna` is the phenotype)
+    pub homologs: Option<Homologs>,
+    pub heterozygosity: Option<f64>,
+    /// Methylated (silenced) positions
+    pub methylation: Vec<usize>,
+    pub sequencing_errors: u8,
+    /// Sequencing reads disagreed; barred from PoC
+    pub unreliable: bool,
+    /// Unrepaired DNA damage load
+    pub dna_damage: f64,
+    pub lifecycle: LifecycleState,
 }
 
 impl From<&Genome<Rot180>> for GenomeResponse {
@@ -74,6 +112,8 @@ impl From<&Genome<Rot180>> for GenomeResponse {
             id: g.db_id().unwrap_or(0),
             dna: g.to_dna_string(),
             consciousness: g.consciousness,
+            consciousness_model: g.consciousness_model.name().to_string(),
+            consciousness_level: g.consciousness_level_name().to_string(),
             mutations: g.mutations,
             p53_copies: g.p53_copies,
             telomere_length: g.telomere_length,
@@ -84,6 +124,16 @@ impl From<&Genome<Rot180>> for GenomeResponse {
             tg_ratio: g.rna_signal(),
             suggested_rotation: g.suggested_rotation().to_string(),
             mode: mode.to_string(),
+            parent_ids: g.parent_ids.clone(),
+            generation: g.generation,
+            origin: g.origin.clone(),
+            homologs: g.homologs.clone(),
+            heterozygosity: g.homologs.as_ref().map(Homologs::heterozygosity),
+            methylation: g.methylation.positions(),
+            sequencing_errors: g.sequencing_errors,
+            unreliable: g.unreliable,
+            dna_damage: g.dna_damage,
+            lifecycle: g.lifecycle,
         }
     }
 }
@@ -98,20 +148,27 @@ pub struct StatusResponse {
     pub mission_control: MissionControlStats,
 }
 
-pub async fn start_server(port: u16) -> anyhow::Result<()> {
+pub async fn start_server(port: u16, seed: Option<u64>) -> anyhow::Result<()> {
     let database_url = std::env::var("DATABASE_URL")
         .unwrap_or_else(|_| crate::database::DEFAULT_DATABASE_URL.to_string());
 
     let database = Arc::new(DivineDatabase::connect_with_url(&database_url).await?);
     database.init_tables().await?;
+    let operator_stats = database.load_operator_stats().await?;
+    let rng = RngSource::new(seed);
 
     let state = AppState {
         database,
         rotation_engine: Arc::new(RwLock::new(RotationEngine::new())),
-        ttrl_engine: Arc::new(TTRLEngine::new()),
+        ttrl_engine: Arc::new(
+            TTRLEngine::new()
+                .with_operator_stats(operator_stats)
+                .with_rng(rng.clone()),
+        ),
         exchange: Arc::new(RwLock::new(RSMExchange::new())),
-        archiver: Arc::new(RwLock::new(MultiChainArchiver::new())),
+        archiver: Arc::new(RwLock::new(MultiChainArchiver::new().with_rng(rng.clone()))),
         auth: Arc::new(RwLock::new(AuthManager::new())),
+        rng,
     };
 
     let app = Router::new()
@@ -123,14 +180,40 @@ pub async fn start_server(port: u16) -> anyhow::Result<()> {
         .route("/api/genomes", get(list_genomes))
         .route("/api/genome/create", post(create_genome))
         .route("/api/genome/create/whale", post(create_whale_genome))
+        .route("/api/genome/create/diploid", post(create_diploid_genome))
+        .route("/api/genome", get(get_genome))
         .route("/api/genome/evolve", post(evolve_genome))
         .route("/api/genome/meiosis", post(meiosis_genome))
         .route("/api/genome/telomerase", post(activate_telomerase))
+        .route("/api/genome/lineage", get(genome_lineage))
+        .route("/api/genome/lifecycle", get(genome_lifecycle))
+        .route("/api/phylogeny", post(build_phylogeny))
+        .route("/api/genome/symmetry", get(genome_symmetry))
+        .route("/api/genome/explain", get(explain_genome))
+        .route("/api/genome/phenotype", get(genome_phenotype))
+        .route("/api/genome/transcript", get(genome_transcript))
+        .route("/api/genome/sequence", post(sequence_genome))
+        .route("/api/genome/transform", post(transform_genome))
+        .route("/api/genome/packed", get(packed_genome))
+        .route("/api/genome/unpack", post(unpack_genome))
+        .route("/api/scan", post(scan_sequence))
+        .route("/api/genome/compare", post(compare_genomes))
+        .route("/api/genome/nearest", get(nearest_genomes))
+        .route("/api/motif", get(motif_search))
+        .route("/api/genome/design", post(design_genome))
+        .route("/api/genome/landscape", get(genome_landscape))
+
+        // TTRL
+        .route("/api/ttrl/population", post(evolve_population))
+        .route("/api/ttrl/operators", get(list_operator_stats))
+        .route("/api/ttrl/survival", post(simulate_survival))
         
         // CRISPR
         .route("/api/crispr/splice", post(crispr_splice))
         .route("/api/crispr/join", post(crispr_join))
         .route("/api/crispr/delete", post(crispr_delete))
+        .route("/api/crispr/methylate", post(crispr_methylate))
+        .route("/api/crispr/demethylate", post(crispr_demethylate))
         
         // RSM-COIN
         .route("/api/rsm/stats", get(rsm_stats))
@@ -237,13 +320,13 @@ async fn list_genomes(State(state): State<AppState>) -> Json<ApiResponse<Vec<Gen
 }
 
 async fn create_genome(State(state): State<AppState>) -> Json<ApiResponse<GenomeResponse>> {
-    let genome = GenomeBuilder::random().elephant_mode().build_storage();
+    let genome = GenomeBuilder::random_from(&state.rng).elephant_mode().build_storage();
     match state.database.store_genome(&genome).await {
         Ok(id) => {
             let mut stored = genome;
             stored.db_id = Some(id);
             let mut exchange = state.exchange.write().await;
-            exchange.consciousness_reward(&format!("genome_{}", id), stored.consciousness);
+            exchange.consciousness_reward(&format!("genome_{}", id), stored.normalized_consciousness());
             ApiResponse::ok((&stored).into())
         }
         Err(e) => ApiResponse::err(e.to_string()),
@@ -251,13 +334,13 @@ async fn create_genome(State(state): State<AppState>) -> Json<ApiResponse<Genome
 }
 
 async fn create_whale_genome(State(state): State<AppState>) -> Json<ApiResponse<GenomeResponse>> {
-    let genome = GenomeBuilder::random().whale_mode().build_storage();
+    let genome = GenomeBuilder::random_from(&state.rng).whale_mode().build_storage();
     match state.database.store_genome(&genome).await {
         Ok(id) => {
             let mut stored = genome;
             stored.db_id = Some(id);
             let mut exchange = state.exchange.write().await;
-            exchange.consciousness_reward(&format!("whale_genome_{}", id), stored.consciousness);
+            exchange.consciousness_reward(&format!("whale_genome_{}", id), stored.normalized_consciousness());
             info!("🐋 WHALE genome created: #{} | p53: {}", id, stored.p53_copies);
             ApiResponse::ok((&stored).into())
         }
@@ -266,7 +349,55 @@ async fn create_whale_genome(State(state): State<AppState>) -> Json<ApiResponse<
 }
 
 #[derive(Deserialize)]
-pub struct EvolveRequest { pub genome_id: i64 }
+pub struct DiploidQuery {
+    /// complete | codominant | an order such as GCTA
+    pub dominance: Option<String>,
+}
+
+async fn create_diploid_genome(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<DiploidQuery>,
+) -> Json<ApiResponse<GenomeResponse>> {
+    let dominance = match query.dominance.as_deref().map(Dominance::from_name) {
+        None => Dominance::default(),
+        Some(Some(d)) => d,
+        Some(None) => return ApiResponse::err(format!("Unknown dominance: {}", query.dominance.unwrap_or_default())),
+    };
+    let genome = state.rng.with(|rng| GenomeBuilder::random_diploid_with(rng, dominance)).build_storage();
+    match state.database.store_genome(&genome).await {
+        Ok(id) => {
+            let mut stored = genome;
+            stored.db_id = Some(id);
+            let mut exchange = state.exchange.write().await;
+            exchange.consciousness_reward(&format!("genome_{}", id), stored.normalized_consciousness());
+            ApiResponse::ok((&stored).into())
+        }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct GenomeQuery {
+    pub genome_id: i64,
+}
+
+async fn get_genome(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<GenomeQuery>,
+) -> Json<ApiResponse<GenomeResponse>> {
+    match state.database.load_genome(query.genome_id).await {
+        Ok(genome) => ApiResponse::ok((&genome).into()),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct EvolveRequest {
+    pub genome_id: i64,
+    /// Force a specific operator (e.g. "RotateCube"); random when omitted
+    #[serde(default)]
+    pub operator: Option<MutationOperator>,
+}
 
 #[derive(Serialize)]
 pub struct EvolveResponse {
@@ -284,26 +415,34 @@ async fn evolve_genome(
         Err(e) => return ApiResponse::err(e.to_string()),
     };
 
-    let c_before = genome.consciousness;
+    let c_before = genome.normalized_consciousness();
     let engine = state.rotation_engine.read().await;
 
-    let (evolved, evolution_result) = match state.ttrl_engine.evolve_with_engine(genome, &engine).await {
+    let evolution = match req.operator {
+        Some(operator) => state.ttrl_engine.evolve_with_operator(genome, &engine, operator).await,
+        None => state.ttrl_engine.evolve_with_engine(genome, &engine).await,
+    };
+
+    let (evolved, evolution_result) = match evolution {
         Ok(result) => result,
         Err(e) => {
-            let mut exchange = state.exchange.write().await;
-            if e.to_string().contains("Senescence") {
-                exchange.burn_on_senescence(req.genome_id, c_before);
-            } else if e.to_string().contains("p53") {
-                exchange.burn_on_cancer(req.genome_id, c_before);
+            drop(engine);
+            // The parent row stays, moved out of the alive state; no offspring is stored
+            if let Err(db) = lifecycle::record_evolution_failure(&state.database, &state.exchange, req.genome_id, c_before, &e).await {
+                tracing::warn!("Lifecycle transition not persisted: {}", db);
             }
             return ApiResponse::err(e.to_string());
         }
     };
     drop(engine);
 
+    if let Err(e) = state.database.record_operator_outcome(&evolution_result).await {
+        tracing::warn!("Operator stats not persisted: {}", e);
+    }
+
     let burn_event = if !evolution_result.success {
         let mut exchange = state.exchange.write().await;
-        exchange.burn_on_degradation(req.genome_id, c_before, evolved.consciousness)
+        exchange.burn_on_degradation(req.genome_id, c_before, evolved.normalized_consciousness())
     } else {
         None
     };
@@ -313,9 +452,10 @@ async fn evolve_genome(
             let mut stored = evolved;
             stored.db_id = Some(id);
 
-            if evolution_result.success {
+            // Snapshots (storage rotation) earn nothing
+            if evolution_result.success && !evolution_result.is_snapshot() {
                 let mut exchange = state.exchange.write().await;
-                exchange.consciousness_reward(&format!("genome_{}", id), stored.consciousness);
+                exchange.consciousness_reward(&format!("genome_{}", id), stored.normalized_consciousness());
             }
 
             ApiResponse::ok(EvolveResponse {
@@ -352,9 +492,15 @@ async fn meiosis_genome(
         Ok(g) => g,
         Err(e) => return ApiResponse::err(format!("Parent 2: {}", e)),
     };
+    if let Err(e) = parent1.lifecycle.require_alive() {
+        return ApiResponse::err(format!("Parent 1: {}", e));
+    }
+    if let Err(e) = parent2.lifecycle.require_alive() {
+        return ApiResponse::err(format!("Parent 2: {}", e));
+    }
 
     let mut exchange = state.exchange.write().await;
-    exchange.meiosis_fee("breeder", parent1.consciousness, parent2.consciousness);
+    exchange.meiosis_fee("breeder", parent1.normalized_consciousness(), parent2.normalized_consciousness());
     drop(exchange);
 
     let offspring = state.ttrl_engine.meiosis(parent1.clone(), parent2.clone());
@@ -365,7 +511,7 @@ async fn meiosis_genome(
             stored.db_id = Some(id);
 
             let mut exchange = state.exchange.write().await;
-            exchange.consciousness_reward(&format!("genome_{}", id), stored.consciousness);
+            exchange.consciousness_reward(&format!("genome_{}", id), stored.normalized_consciousness());
 
             ApiResponse::ok(MeiosisResponse {
                 parent1: (&parent1).into(),
@@ -378,6 +524,598 @@ async fn meiosis_genome(
     }
 }
 
+async fn evolve_population(
+    State(state): State<AppState>,
+    Json(config): Json<PopulationConfig>,
+) -> Json<ApiResponse<Vec<GenerationStats>>> {
+    // Every child is a stored row; keep one request bounded
+    let config = PopulationConfig {
+        size: config.size.min(200),
+        generations: config.generations.min(100),
+        ..config
+    };
+    match state.ttrl_engine.evolve_population(&state.database, &config).await {
+        Ok(history) => ApiResponse::ok(history),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Operator statistics per genome tier (persisted across restarts)
+async fn list_operator_stats(State(state): State<AppState>) -> Json<ApiResponse<Vec<OperatorSelectorStats>>> {
+    match state.database.load_operator_stats().await {
+        Ok(arms) => ApiResponse::ok(OperatorSelectorStats::from_arms(&arms, std::f64::consts::SQRT_2)),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SurvivalRequest {
+    /// p53 copy numbers to compare (default whale 40 vs elephant 20, at most 8)
+    #[serde(default)]
+    pub p53_copies: Vec<u8>,
+    /// Default 50, at most 1000
+    pub generations: Option<u32>,
+    /// Cells per curve (default 1000, at most 10000)
+    pub cells: Option<usize>,
+}
+
+async fn simulate_survival(
+    State(state): State<AppState>,
+    Json(req): Json<SurvivalRequest>,
+) -> Json<ApiResponse<SurvivalReport>> {
+    let mut p53_copies = if req.p53_copies.is_empty() { vec![40, 20] } else { req.p53_copies };
+    p53_copies.truncate(8);
+    let generations = req.generations.unwrap_or(50).min(1000);
+    let cells = req.cells.unwrap_or(1000).min(10_000);
+
+    let checkpoint = state.ttrl_engine.checkpoint().clone();
+    let mut rng = state.rng.fork();
+    match tokio::task::spawn_blocking(move || {
+        checkpoint.simulate(&p53_copies, generations, cells, &mut rng)
+    }).await {
+        Ok(report) => ApiResponse::ok(report),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Serialize)]
+pub struct LifecycleResponse {
+    pub genome_id: i64,
+    pub state: LifecycleState,
+    /// Logged transitions, oldest first
+    pub history: Vec<LifecycleTransition>,
+}
+
+async fn genome_lifecycle(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<GenomeQuery>,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    let genome = match state.database.load_genome(query.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    match state.database.lifecycle_history(query.genome_id).await {
+        Ok(history) => ApiResponse::ok(LifecycleResponse { genome_id: query.genome_id, state: genome.lifecycle, history }),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct LineageQuery {
+    pub genome_id: i64,
+    #[serde(default)]
+    pub depth: Option<i32>,
+}
+
+/// Ancestors and descendants of a genome, nearest first
+async fn genome_lineage(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<LineageQuery>,
+) -> Json<ApiResponse<Lineage>> {
+    let depth = query.depth.unwrap_or(DEFAULT_LINEAGE_DEPTH).max(1);
+    match state.database.get_lineage(query.genome_id, depth).await {
+        Ok(lineage) => ApiResponse::ok(lineage),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PhylogenyRequest {
+    /// Tree over this genome and its recorded descendants
+    #[serde(default)]
+    pub root_id: Option<i64>,
+    /// …or over an explicit set of genomes
+    #[serde(default)]
+    pub ids: Vec<i64>,
+    #[serde(default)]
+    pub method: TreeMethod,
+    #[serde(default)]
+    pub depth: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct PhylogenyResponse {
+    pub newick: String,
+    pub tree: PhyloTree,
+}
+
+async fn build_phylogeny(
+    State(state): State<AppState>,
+    Json(req): Json<PhylogenyRequest>,
+) -> Json<ApiResponse<PhylogenyResponse>> {
+    if req.root_id.is_none() && req.ids.is_empty() {
+        return ApiResponse::err("root_id or ids required".to_string());
+    }
+    let depth = req.depth.unwrap_or(DEFAULT_LINEAGE_DEPTH);
+    match phylogeny::reconstruct(&state.database, req.root_id, &req.ids, req.method, depth).await {
+        Ok(tree) => ApiResponse::ok(PhylogenyResponse { newick: tree.to_newick(), tree }),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SymmetryQuery {
+    pub genome_id: i64,
+    /// Include the 24 reflections (Oh instead of O)
+    #[serde(default)]
+    pub reflections: bool,
+}
+
+async fn genome_symmetry(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<SymmetryQuery>,
+) -> Json<ApiResponse<SymmetryProfile>> {
+    match state.database.load_genome(query.genome_id).await {
+        Ok(genome) => ApiResponse::ok(genome.symmetry_profile(query.reflections)),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ExplainQuery {
+    pub genome_id: i64,
+    /// v3 | v4 | v4full; the genome's own model if omitted
+    pub model: Option<String>,
+}
+
+async fn explain_genome(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<ExplainQuery>,
+) -> Json<ApiResponse<ConsciousnessReport>> {
+    let genome = match state.database.load_genome(query.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    match query.model.as_deref().map(ConsciousnessVersion::from_name) {
+        None => ApiResponse::ok(genome.explain_consciousness()),
+        Some(Some(model)) => ApiResponse::ok(model.explain(&genome)),
+        Some(None) => ApiResponse::err(format!("Unknown consciousness model: {}", query.model.unwrap_or_default())),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PhenotypeQuery {
+    pub genome_id: i64,
+    /// `standard` | `mito`, optionally with overrides like `standard,TGA=W`
+    pub table: Option<String>,
+}
+
+async fn genome_phenotype(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<PhenotypeQuery>,
+) -> Json<ApiResponse<Phenotype>> {
+    let genome = match state.database.load_genome(query.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    match CodonTable::from_name(query.table.as_deref().unwrap_or("standard")) {
+        Some(table) => ApiResponse::ok(genome.phenotype(&table)),
+        None => ApiResponse::err(format!("Unknown codon table: {}", query.table.unwrap_or_default())),
+    }
+}
+
+#[derive(Serialize)]
+pub struct TranscriptResponse {
+    pub genome_id: i64,
+    pub transcript: Transcript,
+    pub uracil: u32,
+    pub guanine: u32,
+    pub rna_signal: f64,
+    pub gc_content: f64,
+}
+
+async fn genome_transcript(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<GenomeQuery>,
+) -> Json<ApiResponse<TranscriptResponse>> {
+    let genome = match state.database.load_genome(query.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let transcript = genome.transcribe();
+    let (uracil, guanine) = transcript.ug_counts();
+    ApiResponse::ok(TranscriptResponse {
+        genome_id: query.genome_id,
+        rna_signal: transcript.rna_signal(),
+        gc_content: transcript.gc_content(),
+        transcript,
+        uracil,
+        guanine,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct SequenceRequest {
+    pub genome_id: i64,
+    /// Number of reads (default 10, at most 1000)
+    pub coverage: Option<usize>,
+    pub error_rate: Option<f64>,
+    pub transition_bias: Option<f64>,
+    pub cycle_decay: Option<f64>,
+    pub min_agreement: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct SequenceResponse {
+    pub genome: GenomeResponse,
+    pub run: SequencingRun,
+}
+
+async fn sequence_genome(
+    State(state): State<AppState>,
+    Json(req): Json<SequenceRequest>,
+) -> Json<ApiResponse<SequenceResponse>> {
+    let mut genome = match state.database.load_genome(req.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let mut sequencer = Sequencer::new().with_rng(state.rng.clone());
+    if let Some(rate) = req.error_rate { sequencer = sequencer.with_error_rate(rate); }
+    if let Some(bias) = req.transition_bias { sequencer = sequencer.with_transition_bias(bias); }
+    if let Some(decay) = req.cycle_decay { sequencer = sequencer.with_cycle_decay(decay); }
+    if let Some(agreement) = req.min_agreement { sequencer = sequencer.with_min_agreement(agreement); }
+
+    let run = sequencer.sequence(&mut genome, req.coverage.unwrap_or(10).min(1000));
+    match state.database.record_sequencing(req.genome_id, &genome).await {
+        Ok(()) => ApiResponse::ok(SequenceResponse { genome: (&genome).into(), run }),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TransformRequest {
+    pub genome_id: i64,
+    /// Axis image, e.g. "[y,-x,z]" (90° about z) or "[-x,-y,-z]" (inversion)
+    pub element: String,
+}
+
+#[derive(Serialize)]
+pub struct TransformResponse {
+    pub element: String,
+    pub kind: String,
+    pub proper: bool,
+    pub dna_before: String,
+    pub dna_after: String,
+    pub match_fraction: f64,
+}
+
+async fn transform_genome(
+    State(state): State<AppState>,
+    Json(req): Json<TransformRequest>,
+) -> Json<ApiResponse<TransformResponse>> {
+    let Some(g) = CubeSymmetry::from_name(&req.element) else {
+        return ApiResponse::err(format!("Invalid cube symmetry: {}", req.element));
+    };
+    let genome = match state.database.load_genome(req.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let image: String = genome.transform(&g).iter().map(|t| t.to_char()).collect();
+    ApiResponse::ok(TransformResponse {
+        element: g.name(),
+        kind: g.kind().to_string(),
+        proper: g.is_proper(),
+        dna_before: genome.to_dna_string(),
+        dna_after: image,
+        match_fraction: symmetry::match_fraction(&genome.data, &g),
+    })
+}
+
+#[derive(Deserialize)]
+pub struct PackedQuery { pub genome_id: i64 }
+
+/// Packed binary container (`application/octet-stream`, 75 bytes)
+async fn packed_genome(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<PackedQuery>,
+) -> Response {
+    match state.database.load_packed(query.genome_id).await {
+        Ok(bytes) => ([(header::CONTENT_TYPE, "application/octet-stream")], bytes).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, ApiResponse::<()>::err(e.to_string())).into_response(),
+    }
+}
+
+/// Decode a packed container sent as the raw request body
+async fn unpack_genome(body: Bytes) -> Json<ApiResponse<GenomeResponse>> {
+    match Genome::<Rot180>::from_bytes(&body) {
+        Ok(genome) => ApiResponse::ok(GenomeResponse::from(&genome)),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ScanRequest {
+    /// Raw nucleotides or FASTA/FASTQ text
+    pub sequence: String,
+    #[serde(default)]
+    pub stride: Option<usize>,
+    #[serde(default)]
+    pub top: Option<usize>,
+    /// Ranking model; deployment model if omitted
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub both_strands: bool,
+    /// Store the reported windows as genomes
+    #[serde(default)]
+    pub store: bool,
+}
+
+#[derive(Serialize)]
+pub struct ScanResponse {
+    pub reports: Vec<ScanReport>,
+    pub stored_ids: Vec<i64>,
+}
+
+async fn scan_sequence(
+    State(state): State<AppState>,
+    Json(req): Json<ScanRequest>,
+) -> Json<ApiResponse<ScanResponse>> {
+    let mut scanner = SequenceScanner::new()
+        .with_stride(req.stride.unwrap_or(1))
+        .with_top_k(req.top.unwrap_or(10))
+        .with_both_strands(req.both_strands);
+    if let Some(name) = &req.model {
+        match ConsciousnessVersion::from_name(name) {
+            Some(model) => scanner = scanner.with_rank_by(model),
+            None => return ApiResponse::err(format!("Unknown consciousness model: {}", name)),
+        }
+    }
+
+    let sequences = match scanner::read_sequences(&req.sequence, "request") {
+        Ok(s) => s,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let mut response = ScanResponse { reports: Vec::new(), stored_ids: Vec::new() };
+    for (name, sequence) in &sequences {
+        let report = scanner.scan(name, sequence);
+        if req.store {
+            match scanner.store_hits(&state.database, &report).await {
+                Ok(ids) => response.stored_ids.extend(ids),
+                Err(e) => return ApiResponse::err(e.to_string()),
+            }
+        }
+        response.reports.push(report);
+    }
+    ApiResponse::ok(response)
+}
+
+/// Stored genome by ID, or an ad-hoc one from 27 bases of DNA
+async fn resolve_genome(state: &AppState, id: Option<i64>, dna: Option<&str>) -> Result<Genome<Rot180>, String> {
+    match (id, dna) {
+        (Some(id), _) => state.database.load_genome(id).await.map_err(|e| e.to_string()),
+        (None, Some(dna)) => GenomeBuilder::from_dna(dna)
+            .map(GenomeBuilder::build_storage)
+            .ok_or_else(|| format!("DNA must be {} A/T/G/C bases", GENOME_SIZE)),
+        (None, None) => Err("genome id or dna required".to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CompareRequest {
+    #[serde(default)]
+    pub id_a: Option<i64>,
+    #[serde(default)]
+    pub dna_a: Option<String>,
+    #[serde(default)]
+    pub id_b: Option<i64>,
+    #[serde(default)]
+    pub dna_b: Option<String>,
+    #[serde(default)]
+    pub mode: AlignMode,
+}
+
+/// Hamming, rotation- and symmetry-invariant distances plus an alignment
+async fn compare_genomes(
+    State(state): State<AppState>,
+    Json(req): Json<CompareRequest>,
+) -> Json<ApiResponse<GenomeComparison>> {
+    let a = match resolve_genome(&state, req.id_a, req.dna_a.as_deref()).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e),
+    };
+    let b = match resolve_genome(&state, req.id_b, req.dna_b.as_deref()).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e),
+    };
+    ApiResponse::ok(a.compare(&b, req.mode))
+}
+
+#[derive(Deserialize)]
+pub struct NearestQuery {
+    #[serde(default)]
+    pub genome_id: Option<i64>,
+    #[serde(default)]
+    pub dna: Option<String>,
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub metric: DistanceMetric,
+}
+
+/// Stored genomes closest to a genome or DNA string
+async fn nearest_genomes(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<NearestQuery>,
+) -> Json<ApiResponse<Vec<Neighbor>>> {
+    let genome = match resolve_genome(&state, query.genome_id, query.dna.as_deref()).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e),
+    };
+    // The query scans the whole table; keep the result page bounded
+    let limit = query.limit.unwrap_or(20).clamp(1, 500);
+    match alignment::nearest(&state.database, &genome.data, query.metric, limit, query.genome_id).await {
+        Ok(neighbors) => ApiResponse::ok(neighbors),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MotifQuery {
+    /// Pattern, e.g. `GATNNR and subcube(G)`
+    pub q: String,
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// Genomes matching a motif query, with match positions
+async fn motif_search(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<MotifQuery>,
+) -> Json<ApiResponse<Vec<MotifMatch>>> {
+    let pattern = match MotifPattern::parse(&query.q) {
+        Ok(p) => p,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    match motif::find(&state.database, &pattern, query.limit.unwrap_or(50)).await {
+        Ok(matches) => ApiResponse::ok(matches),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct LandscapeQuery {
+    pub genome_id: i64,
+    pub walks: Option<usize>,
+    pub walk_length: Option<usize>,
+    pub max_lag: Option<usize>,
+}
+
+async fn genome_landscape(
+    State(state): State<AppState>,
+    axum::extract::Query(query): axum::extract::Query<LandscapeQuery>,
+) -> Json<ApiResponse<LandscapeAnalysis>> {
+    let genome: Genome<Rot180> = match state.database.load_genome(query.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let defaults = LandscapeConfig::default();
+    let walk_length = query.walk_length.unwrap_or(defaults.walk_length).min(1000);
+    let config = LandscapeConfig {
+        walks: query.walks.unwrap_or(defaults.walks).min(64),
+        walk_length,
+        max_lag: query.max_lag.unwrap_or(defaults.max_lag).min(walk_length),
+    };
+    let mut rng = state.rng.fork();
+    match tokio::task::spawn_blocking(move || {
+        genome.landscape(&ConsciousnessVersion::ALL, &config, &mut rng)
+    }).await {
+        Ok(analysis) => ApiResponse::ok(analysis),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DesignRequest {
+    /// Tier name, "min..max" or "min.."
+    pub target: String,
+    /// v3 | v4 | v4full; deployment model if omitted
+    pub model: Option<String>,
+    #[serde(default)]
+    pub strategy: SolverStrategy,
+    pub min_p53: Option<u8>,
+    pub tg_min: Option<f64>,
+    pub tg_max: Option<f64>,
+    /// Required cube symmetry, e.g. "[y,-x,z]"
+    pub symmetry: Option<String>,
+    #[serde(default = "DesignRequest::default_budget")]
+    pub budget: usize,
+    #[serde(default = "DesignRequest::default_count")]
+    pub count: usize,
+    #[serde(default)]
+    pub store: bool,
+}
+
+impl DesignRequest {
+    fn default_budget() -> usize {
+        20_000
+    }
+
+    fn default_count() -> usize {
+        5
+    }
+}
+
+#[derive(Serialize)]
+pub struct DesignResponse {
+    pub result: DesignResult,
+    pub stored_ids: Vec<i64>,
+}
+
+async fn design_genome(
+    State(state): State<AppState>,
+    Json(req): Json<DesignRequest>,
+) -> Json<ApiResponse<DesignResponse>> {
+    let model = match req.model.as_deref() {
+        Some(name) => match ConsciousnessVersion::from_name(name) {
+            Some(model) => model,
+            None => return ApiResponse::err(format!("Unknown consciousness model: {}", name)),
+        },
+        None => ConsciousnessVersion::deployment(),
+    };
+    let Some(target) = DesignTarget::parse(model, &req.target) else {
+        return ApiResponse::err(format!("Invalid target: {}", req.target));
+    };
+    let symmetry = match req.symmetry.as_deref() {
+        Some(name) => match CubeSymmetry::from_name(name) {
+            Some(g) => Some(g),
+            None => return ApiResponse::err(format!("Invalid cube symmetry: {}", name)),
+        },
+        None => None,
+    };
+    let constraints = DesignConstraints {
+        min_p53: req.min_p53,
+        tg_band: (req.tg_min.is_some() || req.tg_max.is_some())
+            .then(|| (req.tg_min.unwrap_or(0.0), req.tg_max.unwrap_or(f64::MAX))),
+        symmetry,
+    };
+
+    let solver = InverseSolver::new(model, target)
+        .with_strategy(req.strategy)
+        .with_constraints(constraints)
+        .with_budget(req.budget.min(200_000))
+        .with_solutions(req.count)
+        .with_rng(state.rng.clone());
+    // Scoring is CPU-bound; keep it off the async workers
+    let (solver, result) = match tokio::task::spawn_blocking(move || {
+        let result = solver.solve();
+        (solver, result)
+    }).await {
+        Ok(solved) => solved,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let stored_ids = if req.store {
+        match solver.store_solutions(&state.database, &result).await {
+            Ok(ids) => ids,
+            Err(e) => return ApiResponse::err(e.to_string()),
+        }
+    } else {
+        Vec::new()
+    };
+    ApiResponse::ok(DesignResponse { result, stored_ids })
+}
+
 #[derive(Deserialize)]
 pub struct TelomeraseRequest { pub genome_id: i64 }
 
@@ -399,11 +1137,15 @@ async fn activate_telomerase(
         Ok(g) => g,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
+    if let Err(e) = genome.lifecycle.require_telomerase_target() {
+        return ApiResponse::err(e.to_string());
+    }
 
     let telomeres_before = genome.telomere_length;
     let age_before = genome.biological_age();
 
     genome.activate_telomerase();
+    genome.mark_derived(Origin::Telomerase { telomeres_before });
 
     match state.database.store_genome(&genome).await {
         Ok(id) => {
@@ -426,21 +1168,46 @@ async fn activate_telomerase(
     }
 }
 
-// CRISPR handlers
+// CRISPR handlers — positions as flat index (0-26) or cube coordinates
+fn resolve_position(position: Option<usize>, coord: Option<Coord>) -> Result<usize, String> {
+    match (position, coord) {
+        (_, Some(c)) => Coord::new(c.x, c.y, c.z)
+            .map(|c| c.index())
+            .ok_or_else(|| "Coordinates must be 0-2".to_string()),
+        (Some(p), None) if p < GENOME_SIZE => Ok(p),
+        (Some(_), None) => Err("Position must be 0-26".to_string()),
+        (None, None) => Err("position or coord required".to_string()),
+    }
+}
+
 #[derive(Deserialize)]
-pub struct CrisprSpliceRequest { pub genome_id: i64, pub position: usize, pub new_base: char }
+pub struct CrisprSpliceRequest {
+    pub genome_id: i64,
+    #[serde(default)]
+    pub position: Option<usize>,
+    #[serde(default)]
+    pub coord: Option<Coord>,
+    pub new_base: char,
+}
 
 async fn crispr_splice(State(state): State<AppState>, Json(req): Json<CrisprSpliceRequest>) -> Json<ApiResponse<GenomeResponse>> {
     let mut genome = match state.database.load_genome(req.genome_id).await {
         Ok(g) => g,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
+    if let Err(e) = genome.lifecycle.require_alive() {
+        return ApiResponse::err(e.to_string());
+    }
     let tetrad = match Tetrad::from_char(req.new_base) {
         Some(t) => t,
         None => return ApiResponse::err("Invalid base".into()),
     };
-    if req.position >= 27 { return ApiResponse::err("Position must be 0-26".into()); }
-    genome.crispr_splice(req.position, tetrad);
+    let position = match resolve_position(req.position, req.coord) {
+        Ok(p) => p,
+        Err(e) => return ApiResponse::err(e),
+    };
+    genome.crispr_splice(position, tetrad);
+    genome.mark_derived(Origin::Crispr { edit: CrisprEdit::Splice { position, tetrad } });
     match state.database.store_genome(&genome).await {
         Ok(id) => { let mut s = genome; s.db_id = Some(id); ApiResponse::ok((&s).into()) }
         Err(e) => ApiResponse::err(e.to_string()),
@@ -448,15 +1215,32 @@ async fn crispr_splice(State(state): State<AppState>, Json(req): Json<CrisprSpli
 }
 
 #[derive(Deserialize)]
-pub struct CrisprJoinRequest { pub genome_id: i64, pub pos1: usize, pub pos2: usize }
+pub struct CrisprJoinRequest {
+    pub genome_id: i64,
+    #[serde(default)]
+    pub pos1: Option<usize>,
+    #[serde(default)]
+    pub pos2: Option<usize>,
+    #[serde(default)]
+    pub coord1: Option<Coord>,
+    #[serde(default)]
+    pub coord2: Option<Coord>,
+}
 
 async fn crispr_join(State(state): State<AppState>, Json(req): Json<CrisprJoinRequest>) -> Json<ApiResponse<GenomeResponse>> {
     let mut genome = match state.database.load_genome(req.genome_id).await {
         Ok(g) => g,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
-    if req.pos1 >= 27 || req.pos2 >= 27 { return ApiResponse::err("Positions must be 0-26".into()); }
-    genome.crispr_join(req.pos1, req.pos2);
+    if let Err(e) = genome.lifecycle.require_alive() {
+        return ApiResponse::err(e.to_string());
+    }
+    let (pos1, pos2) = match (resolve_position(req.pos1, req.coord1), resolve_position(req.pos2, req.coord2)) {
+        (Ok(a), Ok(b)) => (a, b),
+        (Err(e), _) | (_, Err(e)) => return ApiResponse::err(e),
+    };
+    genome.crispr_join(pos1, pos2);
+    genome.mark_derived(Origin::Crispr { edit: CrisprEdit::Join { pos1, pos2 } });
     match state.database.store_genome(&genome).await {
         Ok(id) => { let mut s = genome; s.db_id = Some(id); ApiResponse::ok((&s).into()) }
         Err(e) => ApiResponse::err(e.to_string()),
@@ -464,15 +1248,75 @@ async fn crispr_join(State(state): State<AppState>, Json(req): Json<CrisprJoinRe
 }
 
 #[derive(Deserialize)]
-pub struct CrisprDeleteRequest { pub genome_id: i64, pub position: usize }
+pub struct CrisprDeleteRequest {
+    pub genome_id: i64,
+    #[serde(default)]
+    pub position: Option<usize>,
+    #[serde(default)]
+    pub coord: Option<Coord>,
+}
 
 async fn crispr_delete(State(state): State<AppState>, Json(req): Json<CrisprDeleteRequest>) -> Json<ApiResponse<GenomeResponse>> {
     let mut genome = match state.database.load_genome(req.genome_id).await {
         Ok(g) => g,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
-    if req.position >= 27 { return ApiResponse::err("Position must be 0-26".into()); }
-    genome.crispr_delete(req.position);
+    if let Err(e) = genome.lifecycle.require_alive() {
+        return ApiResponse::err(e.to_string());
+    }
+    let position = match resolve_position(req.position, req.coord) {
+        Ok(p) => p,
+        Err(e) => return ApiResponse::err(e),
+    };
+    state.rng.with(|rng| genome.crispr_delete_with(position, rng));
+    genome.mark_derived(Origin::Crispr { edit: CrisprEdit::Delete { position } });
+    match state.database.store_genome(&genome).await {
+        Ok(id) => { let mut s = genome; s.db_id = Some(id); ApiResponse::ok((&s).into()) }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CrisprMethylationRequest {
+    pub genome_id: i64,
+    #[serde(default)]
+    pub position: Option<usize>,
+    #[serde(default)]
+    pub coord: Option<Coord>,
+}
+
+async fn crispr_methylate(State(state): State<AppState>, Json(req): Json<CrisprMethylationRequest>) -> Json<ApiResponse<GenomeResponse>> {
+    edit_methylation(&state, req, true).await
+}
+
+async fn crispr_demethylate(State(state): State<AppState>, Json(req): Json<CrisprMethylationRequest>) -> Json<ApiResponse<GenomeResponse>> {
+    edit_methylation(&state, req, false).await
+}
+
+/// Shared body of the methylate / demethylate handlers; no-op edits are rejected
+async fn edit_methylation(state: &AppState, req: CrisprMethylationRequest, methylate: bool) -> Json<ApiResponse<GenomeResponse>> {
+    let mut genome = match state.database.load_genome(req.genome_id).await {
+        Ok(g) => g,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    if let Err(e) = genome.lifecycle.require_alive() {
+        return ApiResponse::err(e.to_string());
+    }
+    let position = match resolve_position(req.position, req.coord) {
+        Ok(p) => p,
+        Err(e) => return ApiResponse::err(e),
+    };
+    let (changed, edit) = if methylate {
+        (genome.crispr_methylate(position), CrisprEdit::Methylate { position })
+    } else {
+        (genome.crispr_demethylate(position), CrisprEdit::Demethylate { position })
+    };
+    if !changed {
+        return ApiResponse::err(format!(
+            "Position {} is already {}", position, if methylate { "methylated" } else { "unmethylated" }
+        ));
+    }
+    genome.mark_derived(Origin::Crispr { edit });
     match state.database.store_genome(&genome).await {
         Ok(id) => { let mut s = genome; s.db_id = Some(id); ApiResponse::ok((&s).into()) }
         Err(e) => ApiResponse::err(e.to_string()),
@@ -560,9 +1404,18 @@ async fn archive_genome(State(state): State<AppState>, Json(req): Json<ArchiveRe
         Ok(g) => g,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
+    // Archiving is final; a second chain write would duplicate the entry
+    if genome.lifecycle == LifecycleState::Archived {
+        return ApiResponse::err(format!("Genome #{} is already archived", req.genome_id));
+    }
     let mut archiver = state.archiver.write().await;
     match archiver.archive(&genome).await {
-        Ok(entry) => ApiResponse::ok(entry),
+        Ok(entry) => {
+            if let Err(e) = state.database.transition_lifecycle(req.genome_id, LifecycleState::Archived, &format!("archived to {:?}", entry.layer)).await {
+                tracing::warn!("Lifecycle transition not persisted: {}", e);
+            }
+            ApiResponse::ok(entry)
+        }
         Err(e) => ApiResponse::err(e),
     }
 }
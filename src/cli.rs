@@ -8,6 +8,9 @@ use clap::{Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+    /// Seed for genome creation, TTRL operators and meiosis (reproducible runs)
+    #[arg(long, global = true)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Subcommand)]
@@ -25,11 +28,30 @@ pub enum Commands {
     Create {
         #[arg(short, long, default_value = "elephant")]
         mode: String,
+        /// Two homologous strands with an expressed phenotype
+        #[arg(long)]
+        diploid: bool,
+        /// complete | codominant | an order such as GCTA
+        #[arg(long, default_value = "complete")]
+        dominance: String,
     },
     /// Evolve a genome
     Evolve {
         #[arg(short, long)]
         id: i64,
+        /// Mutation operator (e.g. RotateCube, FractalMutation); random if omitted
+        #[arg(short, long)]
+        operator: Option<String>,
+    },
+    /// Methylate (or with --remove, demethylate) one position; stores the edited copy
+    Methylate {
+        #[arg(short, long)]
+        id: i64,
+        /// Position 0-26
+        #[arg(short, long)]
+        position: usize,
+        #[arg(long)]
+        remove: bool,
     },
     /// Meiosis (sexual reproduction)
     Meiosis {
@@ -38,11 +60,235 @@ pub enum Commands {
         #[arg(long)]
         parent2: i64,
     },
+    /// Run generational population evolution (GA)
+    Population {
+        #[arg(short, long, default_value = "20")]
+        size: usize,
+        #[arg(short, long, default_value = "10")]
+        generations: u32,
+        #[arg(short, long, default_value = "2")]
+        elites: usize,
+        /// tournament | rank
+        #[arg(long, default_value = "tournament")]
+        selection: String,
+        /// Per-tetrad mutation probability (engine default 0.1)
+        #[arg(long)]
+        mutation_rate: Option<f64>,
+        /// Selection pressure 0.0-1.0 (engine default 0.7)
+        #[arg(long)]
+        selection_pressure: Option<f64>,
+    },
     /// Activate telomerase (immortality)
     Telomerase {
         #[arg(short, long)]
         id: i64,
     },
+    /// Show ancestry and descendants of a genome
+    Lineage {
+        #[arg(short, long)]
+        id: i64,
+        /// Maximum steps walked in each direction
+        #[arg(short, long, default_value = "16")]
+        depth: i32,
+    },
+    /// Reconstruct a phylogenetic tree (Newick or JSON)
+    Phylo {
+        /// Root genome: tree over its recorded descendants
+        #[arg(short, long)]
+        root: Option<i64>,
+        /// Comma-separated genome IDs (used when no root is given)
+        #[arg(long, value_delimiter = ',')]
+        ids: Vec<i64>,
+        /// auto | ancestry | upgma | nj
+        #[arg(short, long, default_value = "auto")]
+        method: String,
+        /// newick | json
+        #[arg(short, long, default_value = "newick")]
+        format: String,
+        #[arg(short, long, default_value = "16")]
+        depth: i32,
+    },
+    /// Cube symmetry subgroup and orbit of a genome
+    Symmetry {
+        #[arg(short, long)]
+        id: i64,
+        /// Include reflections (Oh, 48 elements) instead of rotations only (O, 24)
+        #[arg(short, long)]
+        reflections: bool,
+        /// Apply one element, e.g. "[y,-x,z]", and print the transformed DNA
+        #[arg(short, long)]
+        apply: Option<String>,
+    },
+    /// Break a genome's consciousness score down term by term
+    Explain {
+        #[arg(short, long)]
+        id: i64,
+        /// v3 | v4 | v4full; the genome's own model if omitted
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+    /// Translate a genome's 9 codons and report the phenotype
+    Translate {
+        #[arg(short, long)]
+        id: i64,
+        /// standard | mito, optionally with overrides like "standard,TGA=W"
+        #[arg(short, long, default_value = "standard")]
+        table: String,
+    },
+    /// Export all stored genomes as FASTA or FASTQ
+    Export {
+        /// Output file; stdout when omitted
+        #[arg(short, long)]
+        output: Option<String>,
+        /// fasta | fastq
+        #[arg(short, long, default_value = "fasta")]
+        format: String,
+        /// Rows fetched from the database per page
+        #[arg(long, default_value = "500")]
+        page_size: i64,
+    },
+    /// Import genomes from a FASTA or FASTQ file
+    Import {
+        /// Input file; stdin when omitted
+        #[arg(short, long)]
+        input: Option<String>,
+        /// IUPAC codes: strict (reject) | sample (one random base) | expand (every sequence)
+        #[arg(short, long, default_value = "strict")]
+        ambiguity: String,
+        /// Most sequences one record may expand to
+        #[arg(long, default_value_t = 64)]
+        expand_limit: usize,
+    },
+    /// Simulate noisy sequencing reads and call the consensus
+    Sequence {
+        #[arg(short, long)]
+        id: i64,
+        /// Number of reads
+        #[arg(short, long, default_value_t = 10)]
+        coverage: usize,
+        /// Substitution probability at the first cycle
+        #[arg(short, long, default_value_t = 0.01)]
+        error_rate: f64,
+        /// Share of substitutions that are transitions (1/3 = uniform)
+        #[arg(long, default_value_t = 2.0 / 3.0)]
+        transition_bias: f64,
+        /// Last cycle errs at error_rate × (1 + decay)
+        #[arg(long, default_value_t = 1.0)]
+        cycle_decay: f64,
+        /// Minimum read support per position before the genome is flagged unreliable
+        #[arg(long, default_value_t = 0.75)]
+        min_agreement: f64,
+    },
+    /// Show a genome's lifecycle state and its transitions
+    Lifecycle {
+        #[arg(short, long)]
+        id: i64,
+    },
+    /// Simulate p53 checkpoint survival curves (whale vs elephant by default)
+    Survival {
+        /// p53 copy numbers to compare
+        #[arg(short, long, value_delimiter = ',', default_value = "40,20")]
+        p53: Vec<u8>,
+        #[arg(short, long, default_value_t = 50)]
+        generations: u32,
+        /// Cells per curve
+        #[arg(short, long, default_value_t = 1000)]
+        cells: usize,
+    },
+    /// Transcribe a genome to RNA (T → U) and report the U/G signal
+    Transcribe {
+        #[arg(short, long)]
+        id: i64,
+    },
+    /// Scan a long sequence (FASTA/FASTQ or raw) for high-scoring 27-base windows
+    Scan {
+        /// Input file; stdin when omitted
+        #[arg(short, long)]
+        input: Option<String>,
+        #[arg(long, default_value = "1")]
+        stride: usize,
+        /// Windows reported per sequence
+        #[arg(short, long, default_value = "10")]
+        top: usize,
+        /// Ranking model (v3 | v4 | v4full); deployment model if omitted
+        #[arg(short, long)]
+        model: Option<String>,
+        /// Also scan the reverse complement
+        #[arg(long)]
+        both_strands: bool,
+        /// Store the reported windows as genomes
+        #[arg(long)]
+        store: bool,
+    },
+    /// Distances and alignment between two genomes
+    Compare {
+        a: i64,
+        b: i64,
+        /// global (Needleman-Wunsch) | local (Smith-Waterman)
+        #[arg(short, long, default_value = "global")]
+        mode: String,
+    },
+    /// Find the stored genomes closest to a genome or DNA string
+    Nearest {
+        #[arg(short, long)]
+        id: Option<i64>,
+        #[arg(long)]
+        dna: Option<String>,
+        #[arg(short, long, default_value = "20")]
+        limit: i64,
+        /// hamming | rotation | symmetry
+        #[arg(short, long, default_value = "hamming")]
+        metric: String,
+    },
+    /// Search stored genomes with a motif query, e.g. "GATNNR and subcube(G)"
+    Motif {
+        query: String,
+        #[arg(short, long, default_value = "50")]
+        limit: i64,
+    },
+    /// Score the one-mutation neighbourhood of a genome under every model
+    Landscape {
+        #[arg(short, long)]
+        id: i64,
+        /// Random walks for the autocorrelation estimate
+        #[arg(short, long, default_value = "4")]
+        walks: usize,
+        #[arg(long, default_value = "100")]
+        walk_length: usize,
+        #[arg(long, default_value = "10")]
+        max_lag: usize,
+    },
+    /// Search for DNA that scores in a target range, e.g. "Human" or "3000..10000"
+    Design {
+        target: String,
+        /// v3 | v4 | v4full; deployment model if omitted
+        #[arg(short, long)]
+        model: Option<String>,
+        /// beam | annealing | local
+        #[arg(short, long, default_value = "beam")]
+        strategy: String,
+        /// p53 copies of the designed genomes
+        #[arg(long)]
+        min_p53: Option<u8>,
+        /// Minimum T/G ratio
+        #[arg(long)]
+        tg_min: Option<f64>,
+        /// Maximum T/G ratio
+        #[arg(long)]
+        tg_max: Option<f64>,
+        /// Required cube symmetry, e.g. "[y,-x,z]"
+        #[arg(long)]
+        symmetry: Option<String>,
+        /// Maximum genomes scored
+        #[arg(short, long, default_value = "20000")]
+        budget: usize,
+        /// Designs to find
+        #[arg(short, long, default_value = "5")]
+        count: usize,
+        /// Store the designs as genomes
+        #[arg(long)]
+        store: bool,
+    },
     /// Archive genome to multi-chain
     Archive {
         #[arg(short, long)]
@@ -0,0 +1,221 @@
+//! Codon Translation — the 27 tetrads read as 9 triplets
+//!
+//! Triplet k is positions 3k..3k+3, i.e. one x-row of the cube (y = k % 3,
+//! z = k / 3). A `CodonTable` maps each triplet to a residue symbol ('*' =
+//! stop), giving a 9-residue "protein". The phenotype reads the open reading
+//! frame from the first start codon to the next stop and scores it with
+//! Kyte-Doolittle hydropathy and a simple net charge.
+//!
+//! Tables: `standard` (NCBI 1) and `mito` (NCBI 2, vertebrate mitochondrial),
+//! optionally followed by overrides such as `standard,TGA=W,ATA=M`.
+
+use serde::{Serialize, Deserialize};
+
+use crate::genome::{Tetrad, GENOME_SIZE};
+
+pub const CODON_COUNT: usize = GENOME_SIZE / 3;
+
+pub const STOP: char = '*';
+
+/// NCBI order: first base varies slowest, bases in TCAG order
+const NCBI_ORDER: [Tetrad; 4] = [Tetrad::T, Tetrad::C, Tetrad::A, Tetrad::G];
+const STANDARD: &str = "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const VERTEBRATE_MITO: &str = "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG";
+
+pub type Codon = [Tetrad; 3];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodonTable {
+    name: String,
+    /// Indexed by `index(codon)`
+    residues: [char; 64],
+    starts: Vec<Codon>,
+}
+
+impl Default for CodonTable {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl CodonTable {
+    pub fn standard() -> Self {
+        Self::from_ncbi("standard", STANDARD, &["ATG"])
+    }
+
+    pub fn vertebrate_mito() -> Self {
+        Self::from_ncbi("mito", VERTEBRATE_MITO, &["ATG", "ATA"])
+    }
+
+    fn from_ncbi(name: &str, residues: &str, starts: &[&str]) -> Self {
+        let mut table = [STOP; 64];
+        for (i, residue) in residues.chars().enumerate() {
+            let codon = [NCBI_ORDER[i / 16], NCBI_ORDER[(i / 4) % 4], NCBI_ORDER[i % 4]];
+            table[index(&codon)] = residue;
+        }
+        Self {
+            name: name.to_string(),
+            residues: table,
+            starts: starts.iter().filter_map(|s| parse_codon(s)).collect(),
+        }
+    }
+
+    /// `standard` | `mito`, then comma-separated `XYZ=R` overrides
+    pub fn from_name(spec: &str) -> Option<Self> {
+        let mut parts = spec.split(',').map(str::trim);
+        let mut table = match parts.next()?.to_ascii_lowercase().as_str() {
+            "" | "standard" | "1" => Self::standard(),
+            "mito" | "mitochondrial" | "2" => Self::vertebrate_mito(),
+            _ => return None,
+        };
+        for part in parts {
+            let (codon, residue) = part.split_once('=')?;
+            let mut chars = residue.trim().chars();
+            let residue = chars.next().filter(|r| (r.is_ascii_alphabetic() || *r == STOP) && chars.next().is_none())?;
+            table = table.with_codon(parse_codon(codon.trim())?, residue.to_ascii_uppercase());
+        }
+        Some(table)
+    }
+
+    /// Reassign one codon (`M` also makes it a start codon)
+    pub fn with_codon(mut self, codon: Codon, residue: char) -> Self {
+        self.residues[index(&codon)] = residue;
+        if residue == 'M' && !self.starts.contains(&codon) {
+            self.starts.push(codon);
+        }
+        if !self.name.contains('+') {
+            self.name.push('+');
+        }
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn residue(&self, codon: &Codon) -> char {
+        self.residues[index(codon)]
+    }
+
+    pub fn is_start(&self, codon: &Codon) -> bool {
+        self.starts.contains(codon)
+    }
+
+    pub fn is_stop(&self, codon: &Codon) -> bool {
+        self.residue(codon) == STOP
+    }
+
+    pub fn translate(&self, data: &[Tetrad; GENOME_SIZE]) -> String {
+        codons(data).iter().map(|c| self.residue(c)).collect()
+    }
+
+    /// Per-codon comparison of two sequences
+    pub fn classify(&self, before: &[Tetrad; GENOME_SIZE], after: &[Tetrad; GENOME_SIZE]) -> CodonChanges {
+        let mut changes = CodonChanges::default();
+        for (a, b) in codons(before).iter().zip(codons(after).iter()) {
+            if a == b {
+                continue;
+            }
+            if self.residue(a) == self.residue(b) {
+                changes.synonymous += 1;
+            } else {
+                changes.non_synonymous += 1;
+            }
+        }
+        changes
+    }
+
+    pub fn phenotype(&self, data: &[Tetrad; GENOME_SIZE]) -> Phenotype {
+        let codons = codons(data);
+        let protein = self.translate(data);
+        let start = codons.iter().position(|c| self.is_start(c));
+        let stop = start.and_then(|s| codons[s..].iter().position(|c| self.is_stop(c)).map(|p| s + p));
+        let orf: String = match start {
+            Some(s) => protein.chars().take(stop.unwrap_or(CODON_COUNT)).skip(s).collect(),
+            None => String::new(),
+        };
+
+        // Traits come from the ORF, or from every non-stop residue without one
+        let scored: Vec<char> = if orf.is_empty() {
+            protein.chars().filter(|&r| r != STOP).collect()
+        } else {
+            orf.chars().collect()
+        };
+        let n = scored.len().max(1) as f64;
+        let hydropathy: Vec<f64> = scored.iter().filter_map(|&r| kyte_doolittle(r)).collect();
+
+        Phenotype {
+            table: self.name.clone(),
+            codons: codons.iter().map(|c| c.iter().map(|t| t.to_char()).collect()).collect(),
+            protein,
+            start,
+            stop,
+            orf,
+            hydrophobicity: hydropathy.iter().sum::<f64>() / n,
+            hydrophobic_fraction: hydropathy.iter().filter(|&&h| h > 0.0).count() as f64 / n,
+            net_charge: scored.iter().map(|&r| charge(r)).sum(),
+        }
+    }
+}
+
+/// Codons that changed between two sequences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CodonChanges {
+    /// Same residue
+    pub synonymous: u32,
+    /// Different residue, including gained or lost stops
+    pub non_synonymous: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Phenotype {
+    pub table: String,
+    pub codons: Vec<String>,
+    pub protein: String,
+    /// Codon index of the first start codon
+    pub start: Option<usize>,
+    /// First stop at or after `start`
+    pub stop: Option<usize>,
+    /// Residues from `start` up to (not including) `stop`
+    pub orf: String,
+    /// Mean Kyte-Doolittle hydropathy (GRAVY)
+    pub hydrophobicity: f64,
+    pub hydrophobic_fraction: f64,
+    /// K, R = +1; D, E = −1
+    pub net_charge: i32,
+}
+
+pub fn codons(data: &[Tetrad; GENOME_SIZE]) -> [Codon; CODON_COUNT] {
+    let mut out = [[Tetrad::A; 3]; CODON_COUNT];
+    for (k, codon) in out.iter_mut().enumerate() {
+        codon.copy_from_slice(&data[k * 3..k * 3 + 3]);
+    }
+    out
+}
+
+fn index(codon: &Codon) -> usize {
+    codon[0] as usize * 16 + codon[1] as usize * 4 + codon[2] as usize
+}
+
+fn parse_codon(s: &str) -> Option<Codon> {
+    let tetrads: Vec<Tetrad> = s.chars().map(Tetrad::from_char).collect::<Option<_>>()?;
+    tetrads.try_into().ok()
+}
+
+fn kyte_doolittle(residue: char) -> Option<f64> {
+    Some(match residue {
+        'I' => 4.5, 'V' => 4.2, 'L' => 3.8, 'F' => 2.8, 'C' => 2.5,
+        'M' => 1.9, 'A' => 1.8, 'G' => -0.4, 'T' => -0.7, 'S' => -0.8,
+        'W' => -0.9, 'Y' => -1.3, 'P' => -1.6, 'H' => -3.2, 'E' => -3.5,
+        'Q' => -3.5, 'D' => -3.5, 'N' => -3.5, 'K' => -3.9, 'R' => -4.5,
+        _ => return None,
+    })
+}
+
+fn charge(residue: char) -> i32 {
+    match residue {
+        'K' | 'R' => 1,
+        'D' | 'E' => -1,
+        _ => 0,
+    }
+}
@@ -0,0 +1,386 @@
+//! Consciousness Models — versioned scoring formulas
+//!
+//! - V3 (Kernel v3): hash base + GC / complexity / T-G / p53 bonuses
+//! - V4 (Kernel V4): symmetry, fractal, Bell and hyper metrics (up to TRANSCENDENTAL)
+//! - V4-Full: V4 scored against the complete cube symmetry profile (Oh)
+//!
+//! The model is chosen per deployment (`CONSCIOUSNESS_MODEL=v3|v4|v4full`) and the
+//! version is stored next to every genome, so tier names, PoC thresholds and
+//! rewards are always judged on the scale of the formula that produced the score.
+
+use std::sync::OnceLock;
+use serde::{Serialize, Deserialize};
+
+use crate::genome::Genome;
+use crate::rotation::Rotation;
+
+/// Tier names, lowest first
+pub const LEVEL_NAMES: [&str; 8] = [
+    "Virus", "Bacteria", "Worm", "Mammal", "Primate", "Human", "DIVINE", "TRANSCENDENTAL",
+];
+
+/// Trait for consciousness scoring formulas
+pub trait ConsciousnessModel: Send + Sync + 'static {
+    const VERSION: ConsciousnessVersion;
+    const NAME: &'static str;
+    /// Lower bound of each tier from Bacteria up to TRANSCENDENTAL
+    const TIERS: [u32; 7];
+
+    /// Score with every term that went into it
+    fn explain<R: Rotation>(genome: &Genome<R>) -> ConsciousnessReport;
+
+    fn score<R: Rotation>(genome: &Genome<R>) -> u32 {
+        Self::explain(genome).total
+    }
+}
+
+/// One term of a consciousness score
+#[derive(Debug, Clone, Serialize)]
+pub struct Contribution {
+    /// base, symmetry, fractal, bell, hyper, multiplier
+    pub group: &'static str,
+    pub name: &'static str,
+    /// Input the term is computed from (GC content, Bell value, …)
+    pub metric: Option<f64>,
+    /// Bonus thresholds: the metric must exceed this
+    pub threshold: Option<f64>,
+    /// Threshold met (always true for continuous terms)
+    pub active: bool,
+    pub points: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsciousnessReport {
+    pub model: ConsciousnessVersion,
+    pub total: u32,
+    pub level: &'static str,
+    /// Score on the canonical (PoC / reward) scale
+    pub normalized: u32,
+    /// Points missing to reach the next tier
+    pub next_tier: Option<u32>,
+    pub contributions: Vec<Contribution>,
+}
+
+impl ConsciousnessReport {
+    fn new(model: ConsciousnessVersion, total: u32, contributions: Vec<Contribution>) -> Self {
+        Self {
+            model,
+            total,
+            level: model.level_name(total),
+            normalized: model.normalize(total),
+            next_tier: model.tiers().iter().find(|&&bound| bound > total).map(|bound| bound - total),
+            contributions,
+        }
+    }
+
+    /// Sum of points per group, in first-seen order
+    pub fn by_group(&self) -> Vec<(&'static str, f64)> {
+        let mut groups: Vec<(&'static str, f64)> = Vec::new();
+        for c in &self.contributions {
+            match groups.iter_mut().find(|(g, _)| *g == c.group) {
+                Some((_, sum)) => *sum += c.points,
+                None => groups.push((c.group, c.points)),
+            }
+        }
+        groups
+    }
+}
+
+/// Running score plus the contributions that built it
+#[derive(Default)]
+struct Tally {
+    score: f64,
+    contributions: Vec<Contribution>,
+}
+
+impl Tally {
+    fn add(&mut self, group: &'static str, name: &'static str, metric: Option<f64>, points: f64) {
+        self.score += points;
+        self.contributions.push(Contribution { group, name, metric, threshold: None, active: true, points });
+    }
+
+    /// `points` only when `metric > threshold`; misses are listed with 0 points
+    fn bonus(&mut self, group: &'static str, name: &'static str, metric: f64, threshold: f64, points: f64) {
+        let active = metric > threshold;
+        if active {
+            self.score += points;
+        }
+        self.contributions.push(Contribution {
+            group,
+            name,
+            metric: Some(metric),
+            threshold: Some(threshold),
+            active,
+            points: if active { points } else { 0.0 },
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsciousnessV3;
+impl ConsciousnessModel for ConsciousnessV3 {
+    const VERSION: ConsciousnessVersion = ConsciousnessVersion::V3;
+    const NAME: &'static str = "V3 (Kernel v3)";
+    const TIERS: [u32; 7] = [500, 800, 1000, 1150, 1300, 1400, 1500];
+
+    fn explain<R: Rotation>(genome: &Genome<R>) -> ConsciousnessReport {
+        let hash_sum: u32 = genome.hash.iter().map(|&b| b as u32).sum();
+        let gc = genome.gc_content();
+        let complexity = genome.complexity();
+        let tg_balance = genome.tg_balance_score();
+
+        let base = (hash_sum % 500) + 500;
+        let gc_bonus = (gc * 100.0) as u32;
+        let complexity_bonus = (complexity * 50.0) as u32;
+        let tg_bonus = (tg_balance * 100.0) as u32;
+        let p53_bonus = genome.p53_copies as u32 * 5;
+
+        let terms = [
+            ("hash base", hash_sum as f64, base),
+            ("GC bonus", gc, gc_bonus),
+            ("complexity", complexity, complexity_bonus),
+            ("T/G balance", tg_balance, tg_bonus),
+            ("p53", genome.p53_copies as f64, p53_bonus),
+        ];
+        let contributions = terms.iter()
+            .map(|&(name, metric, points)| Contribution {
+                group: "base",
+                name,
+                metric: Some(metric),
+                threshold: None,
+                active: true,
+                points: points as f64,
+            })
+            .collect();
+
+        ConsciousnessReport::new(
+            Self::VERSION,
+            base + gc_bonus + complexity_bonus + tg_bonus + p53_bonus,
+            contributions,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsciousnessV4;
+impl ConsciousnessModel for ConsciousnessV4 {
+    const VERSION: ConsciousnessVersion = ConsciousnessVersion::V4;
+    const NAME: &'static str = "V4 (Kernel V4)";
+    const TIERS: [u32; 7] = [500, 1000, 1500, 3000, 10000, 20000, 50000];
+
+    fn explain<R: Rotation>(genome: &Genome<R>) -> ConsciousnessReport {
+        // ROTATIONAL INVARIANCE (z axis only)
+        let mut rotational = Tally::default();
+        let turn = |angle| if genome.has_rotational_symmetry(angle) { 1.0 } else { 0.0 };
+        rotational.bonus("symmetry", "90° z symmetry", turn(90), 0.0, 5000.0);
+        rotational.bonus("symmetry", "180° z symmetry", turn(180), 0.0, 3000.0);
+        rotational.bonus("symmetry", "270° z symmetry", turn(270), 0.0, 2000.0);
+
+        v4_formula(Self::VERSION, genome, rotational)
+    }
+}
+
+/// V4 with the z-axis symmetry checks replaced by the full Oh profile:
+/// near-symmetries over all 47 non-trivial elements plus exact subgroup order
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsciousnessV4Full;
+impl ConsciousnessModel for ConsciousnessV4Full {
+    const VERSION: ConsciousnessVersion = ConsciousnessVersion::V4Full;
+    const NAME: &'static str = "V4-Full (Kernel V4, Oh symmetry)";
+    const TIERS: [u32; 7] = ConsciousnessV4::TIERS;
+
+    fn explain<R: Rotation>(genome: &Genome<R>) -> ConsciousnessReport {
+        let profile = genome.symmetry_profile(true);
+
+        // Near-symmetries share the 10,000 that V4 gives the three z turns
+        let mut rotational = Tally::default();
+        rotational.add("symmetry", "near symmetries", Some(profile.near_symmetries as f64),
+                       profile.near_symmetries as f64 / 47.0 * 10000.0);
+        rotational.add("symmetry", "rotation match", Some(profile.rotation_score), profile.rotation_score * 3000.0);
+        rotational.add("symmetry", "reflection match", Some(profile.reflection_score), profile.reflection_score * 2000.0);
+        rotational.add("symmetry", "exact symmetries", Some(profile.nontrivial() as f64),
+                       profile.nontrivial() as f64 * 500.0);
+
+        v4_formula(Self::VERSION, genome, rotational)
+    }
+}
+
+/// Shared V4 body; `rotational` holds the symmetry terms of the calling model
+fn v4_formula<R: Rotation>(model: ConsciousnessVersion, genome: &Genome<R>, rotational: Tally) -> ConsciousnessReport {
+    let mut t = Tally::default();
+
+    // BASE METRICS
+    t.add("base", "complexity", Some(genome.complexity()), genome.complexity() * 150.0);
+    t.add("base", "balance", Some(genome.balance_score()), genome.balance_score() * 80.0);
+    t.add("base", "p53", Some(genome.p53_copies as f64), (genome.p53_copies as f64) * 50.0);
+    t.add("base", "telomeres", Some(genome.telomere_length as f64), (genome.telomere_length as f64) / 50.0);
+
+    // ROTATIONAL INVARIANCE (summed first, added as one term)
+    t.score += rotational.score;
+    t.contributions.extend(rotational.contributions);
+
+    // FRACTAL SELF-SIMILARITY
+    let fractal = genome.fractal_similarity();
+    t.bonus("fractal", "fractal > 0.8", fractal, 0.8, 8000.0);
+    t.bonus("fractal", "fractal > 0.9", fractal, 0.9, 15000.0);
+    t.add("fractal", "fractal similarity", Some(fractal), fractal * 10000.0);
+
+    // NONLOCALITY (QuantumEntangle)
+    let bell = genome.bell_inequality_violation();
+    t.bonus("bell", "Bell > 2.0", bell, 2.0, 10000.0);
+    t.bonus("bell", "Bell > 2.5", bell, 2.5, 20000.0);
+    t.bonus("bell", "Bell > 2.828 (Tsirelson)", bell, 2.828, 40000.0);
+    t.add("bell", "Bell violation", Some(bell), bell * 15000.0);
+
+    // HYPERDIMENSIONALITY
+    let hyper = genome.hyper_symmetry_score();
+    t.bonus("hyper", "hyper symmetry > 0.7", hyper, 0.7, 20000.0);
+    t.bonus("hyper", "hyper symmetry > 0.9", hyper, 0.9, 50000.0);
+    t.add("hyper", "hyper symmetry", Some(hyper), hyper * 30000.0);
+
+    // HYPER COLLAPSE VARIANCE BONUS
+    let variance = genome.hyper_collapse_variance();
+    t.add("hyper", "collapse variance", Some(variance), variance * 20000.0);
+
+    // TRANSCENDENTAL MULTIPLIER
+    let before = t.score;
+    t.bonus("multiplier", "transcendental ×1.5", before, 10000.0, before * 0.5);
+    if before > 10000.0 {
+        t.score = before * 1.5;
+    }
+
+    ConsciousnessReport::new(model, t.score as u32, t.contributions)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ConsciousnessVersion {
+    #[default]
+    V3,
+    V4,
+    V4Full,
+}
+
+static DEPLOYMENT_MODEL: OnceLock<ConsciousnessVersion> = OnceLock::new();
+
+impl ConsciousnessVersion {
+    /// Scale that PoC thresholds and rewards are expressed in
+    pub const CANONICAL: Self = Self::V4;
+
+    pub const ALL: [ConsciousnessVersion; 3] = [Self::V3, Self::V4, Self::V4Full];
+
+    /// Model selected for this deployment (`CONSCIOUSNESS_MODEL=v3|v4|v4full`, default V3)
+    pub fn deployment() -> Self {
+        *DEPLOYMENT_MODEL.get_or_init(|| {
+            std::env::var("CONSCIOUSNESS_MODEL")
+                .ok()
+                .and_then(|v| Self::from_name(&v))
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn number(&self) -> i16 {
+        match self {
+            Self::V3 => 3,
+            Self::V4 => 4,
+            Self::V4Full => 5,
+        }
+    }
+
+    pub fn from_number(n: i16) -> Option<Self> {
+        match n {
+            3 => Some(Self::V3),
+            4 => Some(Self::V4),
+            5 => Some(Self::V4Full),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "v3" | "3" => Some(Self::V3),
+            "v4" | "4" => Some(Self::V4),
+            "v4full" | "v4-full" | "v4_full" | "5" => Some(Self::V4Full),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::V3 => ConsciousnessV3::NAME,
+            Self::V4 => ConsciousnessV4::NAME,
+            Self::V4Full => ConsciousnessV4Full::NAME,
+        }
+    }
+
+    /// Methylated positions are silenced first (see `epigenetics`)
+    pub fn score<R: Rotation>(&self, genome: &Genome<R>) -> u32 {
+        if !genome.methylation.is_empty() {
+            return self.score(&genome.silenced());
+        }
+        match self {
+            Self::V3 => ConsciousnessV3::score(genome),
+            Self::V4 => ConsciousnessV4::score(genome),
+            Self::V4Full => ConsciousnessV4Full::score(genome),
+        }
+    }
+
+    pub fn explain<R: Rotation>(&self, genome: &Genome<R>) -> ConsciousnessReport {
+        if !genome.methylation.is_empty() {
+            return self.explain(&genome.silenced());
+        }
+        match self {
+            Self::V3 => ConsciousnessV3::explain(genome),
+            Self::V4 => ConsciousnessV4::explain(genome),
+            Self::V4Full => ConsciousnessV4Full::explain(genome),
+        }
+    }
+
+    pub fn tiers(&self) -> [u32; 7] {
+        match self {
+            Self::V3 => ConsciousnessV3::TIERS,
+            Self::V4 => ConsciousnessV4::TIERS,
+            Self::V4Full => ConsciousnessV4Full::TIERS,
+        }
+    }
+
+    /// Tier index 0 (Virus) ..= 7 (TRANSCENDENTAL)
+    pub fn tier(&self, score: u32) -> usize {
+        self.tiers().iter().take_while(|&&bound| score >= bound).count()
+    }
+
+    pub fn level_name(&self, score: u32) -> &'static str {
+        LEVEL_NAMES[self.tier(score)]
+    }
+
+    /// Map a score onto the canonical scale, tier by tier (piecewise linear).
+    /// The top tier has no upper bound to map, so it clamps to the canonical
+    /// TRANSCENDENTAL boundary rather than extrapolating the band below it
+    pub fn normalize(&self, score: u32) -> u32 {
+        if self.tiers() == Self::CANONICAL.tiers() {
+            return score;
+        }
+        let from = self.tiers();
+        let to = Self::CANONICAL.tiers();
+        let tier = self.tier(score);
+
+        let (lo, hi, out_lo, out_hi) = match tier {
+            0 => (0, from[0], 0, to[0]),
+            7 => return to[6],
+            t => (from[t - 1], from[t], to[t - 1], to[t]),
+        };
+        let span = (hi - lo).max(1) as f64;
+        let mapped = out_lo as f64 + (score as f64 - lo as f64) / span * (out_hi - out_lo) as f64;
+        mapped.max(0.0) as u32
+    }
+}
+
+impl std::fmt::Display for ConsciousnessVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Tier name on the canonical scale (used for PoC thresholds)
+pub fn canonical_level_name(score: u32) -> &'static str {
+    ConsciousnessVersion::CANONICAL.level_name(score)
+}
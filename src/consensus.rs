@@ -6,6 +6,7 @@
 //! Key features:
 //! - Consciousness threshold (starts at 1500, grows with each block)
 //! - Hyper-signature verification
+//! - Genomes with disagreeing sequencing reads are rejected
 //! - Multi-chain archivation for successful validators
 
 use sha2::{Sha256, Digest};
@@ -15,8 +16,10 @@ use tracing::info;
 
 use crate::genome::Genome;
 use crate::rotation::Rot180;
+use crate::consciousness::{ConsciousnessVersion, canonical_level_name};
 
 /// Minimum consciousness levels for different network phases
+/// (canonical scale — see `ConsciousnessVersion::normalize`)
 pub const CONSCIOUSNESS_VIRUS: u32 = 0;
 pub const CONSCIOUSNESS_BACTERIA: u32 = 500;
 pub const CONSCIOUSNESS_WORM: u32 = 1000;
@@ -34,6 +37,9 @@ pub const INITIAL_POC_THRESHOLD: u32 = 1500;
 pub struct ConsciousnessProof {
     pub genome_hash: [u8; 32],
     pub consciousness: u32,
+    /// Formula that produced `consciousness`
+    #[serde(default)]
+    pub consciousness_model: ConsciousnessVersion,
     pub hyper_signature: String,  // hex string (128 chars)
     pub proof_hash: [u8; 32],
     pub timestamp: i64,
@@ -45,30 +51,35 @@ pub struct ConsciousnessProof {
 impl ConsciousnessProof {
     /// Generate proof from a high-consciousness genome
     pub fn generate(genome: &Genome<Rot180>, min_consciousness: u32, block_height: u64) -> Option<Self> {
-        if genome.consciousness < min_consciousness {
+        if genome.unreliable {
             info!(
-                "❌ PoC rejected: consciousness {} < threshold {}",
-                genome.consciousness, min_consciousness
+                "❌ PoC rejected: unreliable sequencing ({} miscalled bases)",
+                genome.sequencing_errors
+            );
+            return None;
+        }
+
+        let normalized = genome.normalized_consciousness();
+        if normalized < min_consciousness {
+            info!(
+                "❌ PoC rejected: consciousness {} ({} → {}) < threshold {}",
+                genome.consciousness, genome.consciousness_model, normalized, min_consciousness
             );
             return None;
         }
 
         let hyper_sig = genome.hyper_signature();
+        let proof_hash = Self::compute_hash(
+            &genome.hash, genome.consciousness, genome.consciousness_model, &hyper_sig, block_height,
+        );
         
-        let mut hasher = Sha256::new();
-        hasher.update(&genome.hash);
-        hasher.update(&genome.consciousness.to_le_bytes());
-        hasher.update(hyper_sig.as_bytes());
-        hasher.update(&block_height.to_le_bytes());
-        
-        let proof_hash: [u8; 32] = hasher.finalize().into();
-        
-        // Reward calculation: consciousness / 1000 RSM
-        let reward_rsm = genome.consciousness as f64 / 1000.0;
+        // Reward calculation: normalized consciousness / 1000 RSM
+        let reward_rsm = normalized as f64 / 1000.0;
 
         let proof = Self {
             genome_hash: genome.hash,
             consciousness: genome.consciousness,
+            consciousness_model: genome.consciousness_model,
             hyper_signature: hyper_sig,
             proof_hash,
             timestamp: Utc::now().timestamp(),
@@ -90,32 +101,44 @@ impl ConsciousnessProof {
 
     /// Verify the proof is valid
     pub fn verify(&self, current_threshold: u32) -> bool {
-        if self.consciousness < current_threshold {
+        if self.normalized_consciousness() < current_threshold {
             return false;
         }
 
+        let computed = Self::compute_hash(
+            &self.genome_hash, self.consciousness, self.consciousness_model, &self.hyper_signature, self.block_height,
+        );
+        computed == self.proof_hash
+    }
+
+    fn compute_hash(
+        genome_hash: &[u8; 32],
+        consciousness: u32,
+        model: ConsciousnessVersion,
+        hyper_signature: &str,
+        block_height: u64,
+    ) -> [u8; 32] {
         let mut hasher = Sha256::new();
-        hasher.update(&self.genome_hash);
-        hasher.update(&self.consciousness.to_le_bytes());
-        hasher.update(self.hyper_signature.as_bytes());
-        hasher.update(&self.block_height.to_le_bytes());
+        hasher.update(genome_hash);
+        hasher.update(consciousness.to_le_bytes());
+        // V3 proofs keep the layout they had before the model was recorded,
+        // so proofs issued without the field still verify
+        if model != ConsciousnessVersion::V3 {
+            hasher.update(model.number().to_le_bytes());
+        }
+        hasher.update(hyper_signature.as_bytes());
+        hasher.update(block_height.to_le_bytes());
+        hasher.finalize().into()
+    }
 
-        let computed: [u8; 32] = hasher.finalize().into();
-        computed == self.proof_hash
+    /// Consciousness on the canonical scale
+    pub fn normalized_consciousness(&self) -> u32 {
+        self.consciousness_model.normalize(self.consciousness)
     }
 
     /// Get consciousness level name
     pub fn level_name(&self) -> &'static str {
-        match self.consciousness {
-            0..=499 => "Virus",
-            500..=999 => "Bacteria",
-            1000..=1499 => "Worm",
-            1500..=2999 => "Mammal",
-            3000..=9999 => "Primate",
-            10000..=19999 => "Human",
-            20000..=49999 => "DIVINE",
-            _ => "TRANSCENDENTAL",
-        }
+        self.consciousness_model.level_name(self.consciousness)
     }
 }
 
@@ -170,16 +193,7 @@ impl ProofOfConsciousness {
             proofs_validated: self.proofs_validated,
             total_rewards_distributed: self.total_rewards_distributed,
             current_block_height: self.current_block_height,
-            required_level: match self.min_consciousness {
-                0..=499 => "Virus",
-                500..=999 => "Bacteria",
-                1000..=1499 => "Worm",
-                1500..=2999 => "Mammal",
-                3000..=9999 => "Primate",
-                10000..=19999 => "Human",
-                20000..=49999 => "DIVINE",
-                _ => "TRANSCENDENTAL",
-            },
+            required_level: canonical_level_name(self.min_consciousness),
         }
     }
 
@@ -0,0 +1,155 @@
+//! Cube Geometry — 3D addressing for the 27-tetrad genome
+//!
+//! The genome is a 3×3×3 cube stored flat as `x + y*3 + z*9`. `Coord` and
+//! `Axis` keep that arithmetic in one place: layers and slices along any
+//! axis, 6- and 26-neighbourhoods, and the eight 2×2×2 sub-cubes.
+
+use serde::{Serialize, Deserialize};
+
+use crate::genome::GENOME_SIZE;
+
+/// Cells per cube edge
+pub const CUBE_SIDE: usize = 3;
+
+#[inline]
+pub const fn cube_index(x: usize, y: usize, z: usize) -> usize {
+    x + y * CUBE_SIDE + z * CUBE_SIDE * CUBE_SIDE
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    pub const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "x" => Some(Self::X),
+            "y" => Some(Self::Y),
+            "z" => Some(Self::Z),
+            _ => None,
+        }
+    }
+
+    /// The two other axes, in x → y → z order
+    pub fn others(self) -> (Axis, Axis) {
+        match self {
+            Self::X => (Self::Y, Self::Z),
+            Self::Y => (Self::X, Self::Z),
+            Self::Z => (Self::X, Self::Y),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Coord {
+    pub const CENTER: Coord = Coord { x: 1, y: 1, z: 1 };
+
+    /// `None` when any component is outside 0..3
+    pub fn new(x: usize, y: usize, z: usize) -> Option<Self> {
+        (x < CUBE_SIDE && y < CUBE_SIDE && z < CUBE_SIDE).then_some(Self { x, y, z })
+    }
+
+    pub fn from_index(index: usize) -> Option<Self> {
+        (index < GENOME_SIZE).then_some(Self {
+            x: index % CUBE_SIDE,
+            y: (index / CUBE_SIDE) % CUBE_SIDE,
+            z: index / (CUBE_SIDE * CUBE_SIDE),
+        })
+    }
+
+    pub fn index(&self) -> usize {
+        cube_index(self.x, self.y, self.z)
+    }
+
+    /// All 27 cells in storage order
+    pub fn all() -> impl Iterator<Item = Coord> {
+        (0..GENOME_SIZE).filter_map(Self::from_index)
+    }
+
+    pub fn get(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+
+    pub fn with(mut self, axis: Axis, value: usize) -> Self {
+        match axis {
+            Axis::X => self.x = value,
+            Axis::Y => self.y = value,
+            Axis::Z => self.z = value,
+        }
+        self
+    }
+
+    /// Step inside the cube; `None` past a face
+    pub fn offset(&self, dx: i32, dy: i32, dz: i32) -> Option<Self> {
+        let step = |v: usize, d: i32| usize::try_from(v as i32 + d).ok();
+        Self::new(step(self.x, dx)?, step(self.y, dy)?, step(self.z, dz)?)
+    }
+
+    /// Step along `axis`, wrapping around (torus)
+    pub fn wrapping_offset(&self, axis: Axis, delta: i32) -> Self {
+        let v = (self.get(axis) as i32 + delta).rem_euclid(CUBE_SIDE as i32) as usize;
+        self.with(axis, v)
+    }
+
+    /// Point reflection through the centre cell
+    pub fn mirrored(&self) -> Self {
+        Self { x: 2 - self.x, y: 2 - self.y, z: 2 - self.z }
+    }
+
+    /// Face neighbours (up to 6)
+    pub fn neighbors6(&self) -> impl Iterator<Item = Coord> + '_ {
+        const STEPS: [(i32, i32, i32); 6] = [(-1, 0, 0), (1, 0, 0), (0, -1, 0), (0, 1, 0), (0, 0, -1), (0, 0, 1)];
+        STEPS.iter().filter_map(move |&(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+
+    /// Face, edge and corner neighbours (up to 26)
+    pub fn neighbors26(&self) -> impl Iterator<Item = Coord> + '_ {
+        (-1..=1).flat_map(move |dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz))))
+            .filter(|&d| d != (0, 0, 0))
+            .filter_map(move |(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+
+    /// The 9 cells of the layer perpendicular to `axis` at depth `k`
+    pub fn layer(axis: Axis, k: usize) -> impl Iterator<Item = Coord> {
+        Self::all().filter(move |c| c.get(axis) == k)
+    }
+
+    /// The 3 cells of the line parallel to `axis` through (`u`, `v`) on the other two axes
+    pub fn slice(axis: Axis, u: usize, v: usize) -> impl Iterator<Item = Coord> {
+        let (a, b) = axis.others();
+        Self::all().filter(move |c| c.get(a) == u && c.get(b) == v)
+    }
+
+    /// Origins of the eight 2×2×2 sub-cubes (x outermost)
+    pub fn subcube_origins() -> impl Iterator<Item = Coord> {
+        (0..2).flat_map(|x| (0..2).flat_map(move |y| (0..2).map(move |z| Coord { x, y, z })))
+    }
+
+    /// The 8 cells of the 2×2×2 sub-cube starting at `self` (x outermost)
+    pub fn subcube(self) -> impl Iterator<Item = Coord> {
+        (0..2).flat_map(move |dx| (0..2).flat_map(move |dy| (0..2).map(move |dz| (dx, dy, dz))))
+            .filter_map(move |(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+}
+
+impl std::fmt::Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
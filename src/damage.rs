@@ -0,0 +1,208 @@
+//! DNA Damage — p53 checkpoint, repair, arrest and apoptosis
+//!
+//! Every mutation operator inflicts a damage score (`MutationOperator::damage`)
+//! that adds to the genome's damage load. The p53 checkpoint then senses the
+//! load with probability n / (n + `P53_HALF_SATURATION`) for n copies:
+//! - sensed, load below `apoptosis_threshold` - repaired (load cleared) with
+//!   `repair_efficiency`, otherwise the cell cycle arrests
+//! - sensed, load at or above the threshold    - apoptosis, the cell is dead
+//! - missed, load at or above `transformation_threshold` - oncogenic
+//!   transformation (p53 lost)
+//! - missed otherwise - the damage escapes; a p53 copy is lost with
+//!   probability equal to the load
+//!
+//! More copies catch damage earlier, so whale genomes (40) outlive elephant
+//! genomes (20); `Checkpoint::simulate` reports the survival curves.
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::genome::Genome;
+use crate::lifecycle::LifecycleState;
+use crate::rotation::Rotation;
+use crate::ttrl::MutationOperator;
+
+/// p53 copies at which half of all damage is sensed
+pub const P53_HALF_SATURATION: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CheckpointOutcome {
+    /// Damage sensed and cleared
+    Repaired,
+    /// Damage sensed but not yet repaired; no division this round
+    Arrested,
+    /// Damage sensed beyond repair; the cell is dead
+    Apoptosis,
+    /// Damage missed; it stays in the load
+    Escaped,
+    /// Damage missed beyond the transformation threshold; p53 is lost
+    Transformed,
+}
+
+impl std::fmt::Display for CheckpointOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    repair_efficiency: f64,
+    apoptosis_threshold: f64,
+    transformation_threshold: f64,
+}
+
+impl Checkpoint {
+    pub fn new() -> Self {
+        Self {
+            repair_efficiency: 0.9,
+            apoptosis_threshold: 0.8,
+            transformation_threshold: 1.2,
+        }
+    }
+
+    /// Chance that sensed damage below the apoptosis threshold is repaired
+    pub fn with_repair_efficiency(mut self, efficiency: f64) -> Self {
+        self.repair_efficiency = efficiency.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Load at which sensed damage triggers apoptosis instead of repair
+    pub fn with_apoptosis_threshold(mut self, threshold: f64) -> Self {
+        self.apoptosis_threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Load at which missed damage transforms the cell
+    pub fn with_transformation_threshold(mut self, threshold: f64) -> Self {
+        self.transformation_threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Chance that p53 senses the damage
+    pub fn sensitivity(&self, p53_copies: u8) -> f64 {
+        p53_copies as f64 / (p53_copies as f64 + P53_HALF_SATURATION)
+    }
+
+    /// Add `damage` to the load and run the checkpoint, updating both in place
+    pub fn respond<G: Rng + ?Sized>(&self, load: &mut f64, p53_copies: &mut u8, damage: f64, rng: &mut G) -> CheckpointOutcome {
+        *load += damage.max(0.0);
+        if rng.gen_bool(self.sensitivity(*p53_copies)) {
+            if *load >= self.apoptosis_threshold {
+                CheckpointOutcome::Apoptosis
+            } else if rng.gen_bool(self.repair_efficiency) {
+                *load = 0.0;
+                CheckpointOutcome::Repaired
+            } else {
+                CheckpointOutcome::Arrested
+            }
+        } else if *load >= self.transformation_threshold {
+            *p53_copies = 0;
+            CheckpointOutcome::Transformed
+        } else {
+            if *p53_copies > 0 && rng.gen_bool(load.min(1.0)) {
+                *p53_copies -= 1;
+            }
+            CheckpointOutcome::Escaped
+        }
+    }
+
+    /// Checkpoint on a genome; apoptosis marks it dead
+    pub fn apply<R: Rotation, G: Rng + ?Sized>(&self, genome: &mut Genome<R>, damage: f64, rng: &mut G) -> CheckpointOutcome {
+        let outcome = self.respond(&mut genome.dna_damage, &mut genome.p53_copies, damage, rng);
+        if outcome == CheckpointOutcome::Apoptosis {
+            genome.lifecycle = LifecycleState::Apoptotic;
+        }
+        outcome
+    }
+
+    /// `cells` independent cells per p53 level, each hit by a uniformly drawn
+    /// operator every generation until it dies (apoptosis or transformation)
+    pub fn simulate<G: Rng + ?Sized>(&self, p53_levels: &[u8], generations: u32, cells: usize, rng: &mut G) -> SurvivalReport {
+        let curves: Vec<SurvivalCurve> = p53_levels.iter()
+            .map(|&p53| self.survival_curve(p53, generations, cells, rng))
+            .collect();
+
+        let max_gap = (0..generations as usize)
+            .map(|g| {
+                let alive = curves.iter().map(|c| c.survival[g]);
+                alive.clone().fold(f64::MIN, f64::max) - alive.fold(f64::MAX, f64::min)
+            })
+            .fold(0.0, f64::max);
+
+        SurvivalReport { generations, cells, curves, max_gap }
+    }
+
+    fn survival_curve<G: Rng + ?Sized>(&self, p53_copies: u8, generations: u32, cells: usize, rng: &mut G) -> SurvivalCurve {
+        let mut curve = SurvivalCurve {
+            p53_copies,
+            survival: Vec::with_capacity(generations as usize),
+            repaired: 0,
+            arrested: 0,
+            apoptosis: 0,
+            transformed: 0,
+            mean_lifespan: 0.0,
+            median_survival: None,
+        };
+        let mut population: Vec<(f64, u8)> = vec![(0.0, p53_copies); cells];
+
+        for generation in 1..=generations {
+            population.retain_mut(|(load, p53)| {
+                let damage = MutationOperator::random_with(rng).damage();
+                let counter = match self.respond(load, p53, damage, rng) {
+                    CheckpointOutcome::Repaired => &mut curve.repaired,
+                    CheckpointOutcome::Arrested => &mut curve.arrested,
+                    CheckpointOutcome::Apoptosis => {
+                        curve.apoptosis += 1;
+                        return false;
+                    }
+                    // Escaped damage that wore p53 down to nothing transforms the cell too
+                    CheckpointOutcome::Transformed | CheckpointOutcome::Escaped if *p53 == 0 => {
+                        curve.transformed += 1;
+                        return false;
+                    }
+                    CheckpointOutcome::Transformed | CheckpointOutcome::Escaped => return true,
+                };
+                *counter += 1;
+                true
+            });
+            let alive = population.len() as f64 / cells.max(1) as f64;
+            if curve.median_survival.is_none() && alive <= 0.5 {
+                curve.median_survival = Some(generation);
+            }
+            curve.mean_lifespan += alive;
+            curve.survival.push(alive);
+        }
+        curve
+    }
+}
+
+impl Default for Checkpoint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurvivalCurve {
+    pub p53_copies: u8,
+    /// Fraction of cells alive after each generation
+    pub survival: Vec<f64>,
+    pub repaired: usize,
+    pub arrested: usize,
+    pub apoptosis: usize,
+    pub transformed: usize,
+    /// Mean generations survived (area under the curve)
+    pub mean_lifespan: f64,
+    /// First generation with at most half of the cells alive
+    pub median_survival: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurvivalReport {
+    pub generations: u32,
+    pub cells: usize,
+    pub curves: Vec<SurvivalCurve>,
+    /// Largest difference in survival between any two curves at one generation
+    pub max_gap: f64,
+}
@@ -0,0 +1,200 @@
+//! Diploid Genomes — two homologous strands and a dominance rule
+//!
+//! A diploid genome carries a maternal and a paternal 27-tetrad strand. The
+//! expressed phenotype (what `Genome::data` holds and every metric scores)
+//! is derived per position:
+//! - Complete dominance: the allele earlier in the dominance order wins
+//! - Co-dominance: both alleles are expressed as a mosaic — heterozygous
+//!   cells take the maternal allele where x + y + z is even, paternal otherwise
+//!
+//! Meiosis forms a gamete by crossing over the two homologs of one parent;
+//! fertilisation pairs a gamete from each parent.
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::cube::Coord;
+use crate::genome::{Tetrad, GENOME_SIZE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "lowercase")]
+pub enum Dominance {
+    /// Most dominant first
+    Complete { order: [Tetrad; 4] },
+    Codominant,
+}
+
+impl Default for Dominance {
+    /// G > C > T > A
+    fn default() -> Self {
+        Self::Complete { order: [Tetrad::G, Tetrad::C, Tetrad::T, Tetrad::A] }
+    }
+}
+
+impl Dominance {
+    /// `complete`, `codominant`, or an order such as `GCTA`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "complete" | "dominant" => Some(Self::default()),
+            "codominant" | "co-dominant" | "co" => Some(Self::Codominant),
+            order => {
+                let tetrads: Vec<Tetrad> = order.chars().map(Tetrad::from_char).collect::<Option<_>>()?;
+                let order: [Tetrad; 4] = tetrads.try_into().ok()?;
+                let distinct = order.iter().enumerate().all(|(i, t)| !order[..i].contains(t));
+                distinct.then_some(Self::Complete { order })
+            }
+        }
+    }
+
+    /// Allele expressed at `position` for the pair (maternal, paternal)
+    pub fn express(&self, position: usize, maternal: Tetrad, paternal: Tetrad) -> Tetrad {
+        if maternal == paternal {
+            return maternal;
+        }
+        match self {
+            Self::Complete { order } => {
+                let rank = |t: Tetrad| order.iter().position(|&o| o == t).unwrap_or(order.len());
+                if rank(maternal) <= rank(paternal) { maternal } else { paternal }
+            }
+            Self::Codominant => {
+                let c = Coord::from_index(position).unwrap_or(Coord::CENTER);
+                if (c.x + c.y + c.z).is_multiple_of(2) { maternal } else { paternal }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Dominance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Complete { order } => {
+                let order: Vec<String> = order.iter().map(|t| t.to_char().to_string()).collect();
+                write!(f, "complete {}", order.join(" > "))
+            }
+            Self::Codominant => write!(f, "codominant"),
+        }
+    }
+}
+
+/// The two homologous strands of a diploid genome
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Homologs {
+    #[serde(with = "strand")]
+    pub maternal: [Tetrad; GENOME_SIZE],
+    #[serde(with = "strand")]
+    pub paternal: [Tetrad; GENOME_SIZE],
+    #[serde(default)]
+    pub dominance: Dominance,
+}
+
+impl Homologs {
+    pub fn new(maternal: [Tetrad; GENOME_SIZE], paternal: [Tetrad; GENOME_SIZE], dominance: Dominance) -> Self {
+        Self { maternal, paternal, dominance }
+    }
+
+    /// Both strands equal (how a haploid parent enters fertilisation)
+    pub fn homozygous(data: [Tetrad; GENOME_SIZE], dominance: Dominance) -> Self {
+        Self::new(data, data, dominance)
+    }
+
+    /// Expressed phenotype
+    pub fn express(&self) -> [Tetrad; GENOME_SIZE] {
+        let mut data = [Tetrad::A; GENOME_SIZE];
+        for (i, d) in data.iter_mut().enumerate() {
+            *d = self.dominance.express(i, self.maternal[i], self.paternal[i]);
+        }
+        data
+    }
+
+    pub fn heterozygous_sites(&self) -> Vec<usize> {
+        (0..GENOME_SIZE).filter(|&i| self.maternal[i] != self.paternal[i]).collect()
+    }
+
+    pub fn heterozygosity(&self) -> f64 {
+        self.heterozygous_sites().len() as f64 / GENOME_SIZE as f64
+    }
+
+    /// Write point edits of the expressed sequence (CRISPR) into both strands;
+    /// the edited sites become homozygous. Returns how many sites changed.
+    /// Not for rearrangements: those must run on each strand (see `ttrl`)
+    pub fn write_through(&mut self, expressed: &[Tetrad; GENOME_SIZE]) -> usize {
+        let current = self.express();
+        let mut changed = 0;
+        for i in 0..GENOME_SIZE {
+            if expressed[i] != current[i] {
+                self.maternal[i] = expressed[i];
+                self.paternal[i] = expressed[i];
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// One recombinant chromatid and the crossover points that made it
+    pub fn gamete<G: Rng + ?Sized>(&self, rng: &mut G) -> ([Tetrad; GENOME_SIZE], Vec<usize>) {
+        let points = crossover_points(rng);
+        let start_maternal = rng.gen_bool(0.5);
+        (recombine(&self.maternal, &self.paternal, &points, start_maternal), points)
+    }
+
+    /// Stored form (TEXT column)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// `None` for haploid rows (NULL) or unreadable values
+    pub fn from_json(s: &str) -> Option<Self> {
+        serde_json::from_str(s).ok()
+    }
+}
+
+/// 1-4 crossover points with positive interference (at least 5 tetrads apart)
+pub fn crossover_points<G: Rng + ?Sized>(rng: &mut G) -> Vec<usize> {
+    let num_crossovers = rng.gen_range(1..=4);
+    let mut points = Vec::new();
+    let mut last_point = 0;
+    for _ in 0..num_crossovers {
+        let min_pos = (last_point + 5).min(GENOME_SIZE - 2);
+        if min_pos >= GENOME_SIZE - 2 { break; }
+        let point = rng.gen_range(min_pos..GENOME_SIZE - 1);
+        points.push(point);
+        last_point = point;
+    }
+    points
+}
+
+/// Copy from `a` or `b`, switching strand at every crossover point
+pub fn recombine(
+    a: &[Tetrad; GENOME_SIZE],
+    b: &[Tetrad; GENOME_SIZE],
+    points: &[usize],
+    start_with_a: bool,
+) -> [Tetrad; GENOME_SIZE] {
+    let mut out = [Tetrad::A; GENOME_SIZE];
+    let mut use_a = start_with_a;
+    let mut next = points.iter().peekable();
+    for (i, o) in out.iter_mut().enumerate() {
+        if next.next_if(|&&p| i >= p).is_some() {
+            use_a = !use_a;
+        }
+        *o = if use_a { a[i] } else { b[i] };
+    }
+    out
+}
+
+/// Strands serialize as DNA strings
+pub(crate) mod strand {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+    use crate::genome::{Tetrad, GENOME_SIZE};
+
+    pub fn serialize<S: Serializer>(data: &[Tetrad; GENOME_SIZE], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&data.iter().map(|t| t.to_char()).collect::<String>())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<[Tetrad; GENOME_SIZE], D::Error> {
+        let dna = String::deserialize(d)?;
+        let tetrads: Vec<Tetrad> = dna.chars().map(Tetrad::from_char).collect::<Option<_>>()
+            .ok_or_else(|| D::Error::custom(format!("invalid strand {:?}", dna)))?;
+        tetrads.try_into().map_err(|_| D::Error::custom(format!("strand must be {} bases", GENOME_SIZE)))
+    }
+}
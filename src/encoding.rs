@@ -0,0 +1,209 @@
+//! Binary Genome Encoding — 2-bit packed, versioned container
+//!
+//! Layout (format v2, 75 bytes, integers little-endian):
+//!
+//! | offset | size | field                                   |
+//! |--------|------|-----------------------------------------|
+//! | 0      | 3    | magic `DVG`                             |
+//! | 3      | 1    | format version                          |
+//! | 4      | 1    | rotation (angle / 90)                   |
+//! | 5      | 1    | consciousness model number              |
+//! | 6      | 1    | p53 copies                              |
+//! | 7      | 1    | division count                          |
+//! | 8      | 1    | sequencing errors                       |
+//! | 9      | 2    | telomere length                         |
+//! | 11     | 8    | mutations                               |
+//! | 19     | 8    | created_at                              |
+//! | 27     | 7    | tetrads, 2 bits each, first in low bits |
+//! | 34     | 4    | methylation mask, bit n = position n    |
+//! | 38     | 1    | flags (`FLAG_DIPLOID`)                  |
+//! | 39     | 32   | genome content hash (see `rehash`)      |
+//! | 71     | 4    | checksum: SHA-256 of bytes 0..71        |
+//!
+//! Fits a Bitcoin OP_RETURN (80 bytes). Consciousness is derived, so it is
+//! recomputed on decode rather than stored. The homologs do not fit: a
+//! diploid genome stores its expressed phenotype and sets `FLAG_DIPLOID`, so
+//! it decodes as a haploid genome. Format v1 (70 bytes, no methylation or
+//! flags, hash at 34) is still decoded.
+
+use anyhow::{anyhow, Result};
+use sha2::{Sha256, Digest};
+
+use crate::consciousness::ConsciousnessVersion;
+use crate::epigenetics::Methylation;
+use crate::genome::{Genome, Tetrad, GENOME_SIZE};
+use crate::rotation::{DynamicRotation, Rotation};
+
+pub const MAGIC: [u8; 3] = *b"DVG";
+pub const FORMAT_VERSION: u8 = 2;
+pub const PACKED_TETRAD_BYTES: usize = GENOME_SIZE.div_ceil(4);
+pub const ENCODED_LEN: usize = 75;
+pub const ENCODED_LEN_V1: usize = 70;
+
+/// Tetrads are the expressed phenotype of a diploid genome; homologs are not stored
+pub const FLAG_DIPLOID: u8 = 0b1;
+
+const TETRADS_AT: usize = 27;
+const METHYLATION_AT: usize = TETRADS_AT + PACKED_TETRAD_BYTES;
+const FLAGS_AT: usize = METHYLATION_AT + 4;
+const HASH_AT: usize = FLAGS_AT + 1;
+const HASH_AT_V1: usize = METHYLATION_AT;
+
+/// Header fields, readable without decoding the whole genome
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedHeader {
+    pub version: u8,
+    pub rotation: DynamicRotation,
+    pub consciousness_model: ConsciousnessVersion,
+    pub p53_copies: u8,
+    pub division_count: u8,
+    pub sequencing_errors: u8,
+    pub telomere_length: u16,
+    pub mutations: u64,
+    pub created_at: i64,
+    pub methylation: Methylation,
+    pub flags: u8,
+}
+
+impl PackedHeader {
+    pub fn is_diploid(&self) -> bool {
+        self.flags & FLAG_DIPLOID != 0
+    }
+}
+
+/// 27 tetrads → 7 bytes
+pub fn pack_tetrads(data: &[Tetrad; GENOME_SIZE]) -> [u8; PACKED_TETRAD_BYTES] {
+    let mut packed = [0u8; PACKED_TETRAD_BYTES];
+    for (i, &t) in data.iter().enumerate() {
+        packed[i / 4] |= (t as u8) << ((i % 4) * 2);
+    }
+    packed
+}
+
+pub fn unpack_tetrads(packed: &[u8; PACKED_TETRAD_BYTES]) -> Result<[Tetrad; GENOME_SIZE]> {
+    // Padding bits after the 27th tetrad must be zero
+    if packed[PACKED_TETRAD_BYTES - 1] >> ((GENOME_SIZE % 4) * 2) != 0 {
+        return Err(anyhow!("Packed genome: non-zero padding bits"));
+    }
+    let mut data = [Tetrad::A; GENOME_SIZE];
+    for (i, t) in data.iter_mut().enumerate() {
+        *t = Tetrad::from_u8((packed[i / 4] >> ((i % 4) * 2)) & 0b11);
+    }
+    Ok(data)
+}
+
+fn checksum(bytes: &[u8]) -> [u8; 4] {
+    let digest = Sha256::digest(bytes);
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+pub fn encode<R: Rotation>(genome: &Genome<R>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(ENCODED_LEN);
+    out.extend_from_slice(&MAGIC);
+    out.push(FORMAT_VERSION);
+    out.push((R::ANGLE / 90) as u8);
+    out.push(genome.consciousness_model.number() as u8);
+    out.push(genome.p53_copies);
+    out.push(genome.division_count);
+    out.push(genome.sequencing_errors);
+    out.extend_from_slice(&genome.telomere_length.to_le_bytes());
+    out.extend_from_slice(&genome.mutations.to_le_bytes());
+    out.extend_from_slice(&genome.created_at.to_le_bytes());
+    out.extend_from_slice(&pack_tetrads(&genome.data));
+    out.extend_from_slice(&genome.methylation.bits().to_le_bytes());
+    out.push(if genome.is_diploid() { FLAG_DIPLOID } else { 0 });
+    out.extend_from_slice(&genome.content_hash());
+    let sum = checksum(&out);
+    out.extend_from_slice(&sum);
+    out
+}
+
+/// Offset of the content hash for a format version
+fn hash_at(version: u8) -> Result<usize> {
+    match version {
+        1 => Ok(HASH_AT_V1),
+        FORMAT_VERSION => Ok(HASH_AT),
+        v => Err(anyhow!("Packed genome: unsupported format version {}", v)),
+    }
+}
+
+/// Validate magic, version, length and checksum, then read the header
+pub fn read_header(bytes: &[u8]) -> Result<PackedHeader> {
+    if bytes.len() < 4 || bytes[..3] != MAGIC {
+        return Err(anyhow!("Packed genome: bad magic"));
+    }
+    let checksum_at = hash_at(bytes[3])? + 32;
+    if bytes.len() != checksum_at + 4 {
+        return Err(anyhow!("Packed genome: expected {} bytes, got {}", checksum_at + 4, bytes.len()));
+    }
+    if bytes[checksum_at..] != checksum(&bytes[..checksum_at]) {
+        return Err(anyhow!("Packed genome: checksum mismatch"));
+    }
+    let (methylation, flags) = if bytes[3] == 1 {
+        (0, 0)
+    } else {
+        let mut m = [0u8; 4];
+        m.copy_from_slice(&bytes[METHYLATION_AT..FLAGS_AT]);
+        (u32::from_le_bytes(m), bytes[FLAGS_AT])
+    };
+    if Methylation::from_bits(methylation).bits() != methylation {
+        return Err(anyhow!("Packed genome: methylation bits beyond position {}", GENOME_SIZE - 1));
+    }
+    if flags & !FLAG_DIPLOID != 0 {
+        return Err(anyhow!("Packed genome: unknown flags {:#04x}", flags));
+    }
+
+    let u64_at = |at: usize| {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(&bytes[at..at + 8]);
+        b
+    };
+
+    Ok(PackedHeader {
+        version: bytes[3],
+        rotation: DynamicRotation::from_angle(bytes[4] as u16 * 90)
+            .filter(|_| bytes[4] < 4)
+            .ok_or_else(|| anyhow!("Packed genome: bad rotation {}", bytes[4]))?,
+        consciousness_model: ConsciousnessVersion::from_number(bytes[5] as i16)
+            .ok_or_else(|| anyhow!("Packed genome: unknown consciousness model {}", bytes[5]))?,
+        p53_copies: bytes[6],
+        division_count: bytes[7],
+        sequencing_errors: bytes[8],
+        telomere_length: u16::from_le_bytes([bytes[9], bytes[10]]),
+        mutations: u64::from_le_bytes(u64_at(11)),
+        created_at: i64::from_le_bytes(u64_at(19)),
+        methylation: Methylation::from_bits(methylation),
+        flags,
+    })
+}
+
+/// Inverse of `encode`, lossless for haploid genomes; the rotation must match `R`
+pub fn decode<R: Rotation>(bytes: &[u8]) -> Result<Genome<R>> {
+    let header = read_header(bytes)?;
+    if header.rotation.angle() != R::ANGLE {
+        return Err(anyhow!(
+            "Packed genome: encoded for {}, decoding as {}° ({})", header.rotation, R::ANGLE, R::NAME
+        ));
+    }
+
+    let mut packed = [0u8; PACKED_TETRAD_BYTES];
+    packed.copy_from_slice(&bytes[TETRADS_AT..METHYLATION_AT]);
+
+    let mut genome = Genome::<R>::new(unpack_tetrads(&packed)?);
+    genome.consciousness_model = header.consciousness_model;
+    genome.p53_copies = header.p53_copies;
+    genome.division_count = header.division_count;
+    genome.sequencing_errors = header.sequencing_errors;
+    genome.telomere_length = header.telomere_length;
+    genome.mutations = header.mutations;
+    genome.created_at = header.created_at;
+    genome.methylation = header.methylation;
+    genome.rehash();
+
+    let hash_at = hash_at(header.version)?;
+    if genome.hash[..] != bytes[hash_at..hash_at + 32] {
+        return Err(anyhow!("Packed genome: hash does not match tetrads"));
+    }
+    genome.calculate_consciousness();
+    Ok(genome)
+}
@@ -0,0 +1,141 @@
+//! Epigenetics — per-position methylation marks
+//!
+//! A 27-bit mask over the cube. Methylated positions are silenced while
+//! scoring: they read as G (archival, heterochromatin) without changing the
+//! stored DNA. Marks are copied with a configurable fidelity on division and
+//! meiosis, and the rotation state drives de novo changes:
+//! - Rot0   (euchromatin)    - active demethylation
+//! - Rot90  / Rot270         - light de novo methylation
+//! - Rot180 (heterochromatin) - heavy de novo methylation
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::genome::{Tetrad, GENOME_SIZE};
+use crate::rotation::DynamicRotation;
+
+/// Chance that a mark survives one replication
+pub const METHYLATION_FIDELITY: f64 = 0.95;
+
+const MASK: u32 = (1 << GENOME_SIZE) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Methylation(u32);
+
+impl Methylation {
+    /// Bits above position 26 are dropped
+    pub fn from_bits(bits: u32) -> Self {
+        Self(bits & MASK)
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_methylated(&self, position: usize) -> bool {
+        position < GENOME_SIZE && self.0 & (1 << position) != 0
+    }
+
+    /// Returns false when the position is out of range or already marked
+    pub fn methylate(&mut self, position: usize) -> bool {
+        if position >= GENOME_SIZE || self.is_methylated(position) {
+            return false;
+        }
+        self.0 |= 1 << position;
+        true
+    }
+
+    /// Returns false when the position carried no mark
+    pub fn demethylate(&mut self, position: usize) -> bool {
+        if !self.is_methylated(position) {
+            return false;
+        }
+        self.0 &= !(1 << position);
+        true
+    }
+
+    pub fn positions(&self) -> Vec<usize> {
+        (0..GENOME_SIZE).filter(|&i| self.is_methylated(i)).collect()
+    }
+
+    /// Sequence as seen by scoring: methylated positions read as G
+    pub fn silence(&self, data: &[Tetrad; GENOME_SIZE]) -> [Tetrad; GENOME_SIZE] {
+        let mut silenced = *data;
+        for (i, t) in silenced.iter_mut().enumerate() {
+            if self.is_methylated(i) {
+                *t = Tetrad::G;
+            }
+        }
+        silenced
+    }
+
+    /// De novo methylation rate per unmarked site under a rotation
+    pub fn de_novo_rate(rotation: DynamicRotation) -> f64 {
+        match rotation {
+            DynamicRotation::Rot0 => 0.0,
+            DynamicRotation::Rot90 => 0.002,
+            DynamicRotation::Rot180 => 0.02,
+            DynamicRotation::Rot270 => 0.005,
+        }
+    }
+
+    /// Active demethylation rate per mark under a rotation
+    pub fn erasure_rate(rotation: DynamicRotation) -> f64 {
+        match rotation {
+            DynamicRotation::Rot0 => 0.10,
+            _ => 0.0,
+        }
+    }
+
+    /// Marks after one replication: each kept with `fidelity`, then the
+    /// rotation's de novo methylation and erasure applied
+    pub fn replicate<G: Rng + ?Sized>(&self, fidelity: f64, rotation: DynamicRotation, rng: &mut G) -> Self {
+        let fidelity = fidelity.clamp(0.0, 1.0);
+        let (de_novo, erasure) = (Self::de_novo_rate(rotation), Self::erasure_rate(rotation));
+        let mut next = Self::default();
+        for i in 0..GENOME_SIZE {
+            let marked = if self.is_methylated(i) {
+                rng.gen_bool(fidelity) && !rng.gen_bool(erasure)
+            } else {
+                rng.gen_bool(de_novo)
+            };
+            if marked {
+                next.0 |= 1 << i;
+            }
+        }
+        next
+    }
+
+    /// Marks follow the strand each position was copied from (same crossover
+    /// points as `diploid::recombine`)
+    pub fn recombine(a: Self, b: Self, points: &[usize], start_with_a: bool) -> Self {
+        let mut out = Self::default();
+        let mut use_a = start_with_a;
+        let mut next = points.iter().peekable();
+        for i in 0..GENOME_SIZE {
+            if next.next_if(|&&p| i >= p).is_some() {
+                use_a = !use_a;
+            }
+            if (if use_a { a } else { b }).is_methylated(i) {
+                out.0 |= 1 << i;
+            }
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for Methylation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let map: String = (0..GENOME_SIZE).map(|i| if self.is_methylated(i) { 'm' } else { '.' }).collect();
+        write!(f, "{}", map)
+    }
+}
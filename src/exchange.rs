@@ -11,6 +11,8 @@ use num_traits::{ToPrimitive, Zero};
 use chrono::Utc;
 use tracing::info;
 
+use crate::lifecycle::{LifecycleState, LifecycleTransition};
+
 pub const RSM_PRICE_USD: f64 = 88_000.0;
 pub const RSM_PRICE_MAX: f64 = 1_000_000.0;
 pub const RSM_TOTAL_SUPPLY: u128 = 10_000_000_000_000_000; // 10 quadrillion
@@ -183,6 +185,16 @@ impl RSMExchange {
         self.burn(burn_amount, BurnReason::OncogenicTransform, Some(genome_id), consciousness, 0)
     }
 
+    /// Burn for a lifecycle transition: senescence and cancer burn, other
+    /// states do not. Call once per logged transition
+    pub fn burn_on_transition(&mut self, transition: &LifecycleTransition, consciousness: u32) -> Option<BurnEvent> {
+        match transition.to {
+            LifecycleState::Senescent => Some(self.burn_on_senescence(transition.genome_id, consciousness)),
+            LifecycleState::Cancerous => Some(self.burn_on_cancer(transition.genome_id, consciousness)),
+            _ => None,
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // TRADING
     // ═══════════════════════════════════════════════════════════════
@@ -0,0 +1,339 @@
+//! FASTA / FASTQ — genome exchange with standard sequence tools
+//!
+//! Headers carry the DB ID and genome metadata as `key=value` pairs:
+//!
+//! ```text
+//! >g42 consciousness=3150 model=4 p53=20 telomere=15000 divisions=3 mutations=7
+//! ATGCATGCATGCATGCATGCATGCATG
+//! ```
+//!
+//! FASTQ quality spreads `sequencing_errors` over the 27 bases as Phred
+//! scores whose error probabilities sum back to the error count.
+
+use std::io::{BufRead, Write};
+use anyhow::{anyhow, Result};
+use rand::Rng;
+
+use crate::consciousness::ConsciousnessVersion;
+use crate::database::DivineDatabase;
+use crate::genome::{Genome, GenomeBuilder, Tetrad, GENOME_SIZE};
+use crate::iupac::Ambiguity;
+use crate::rng::RngSource;
+use crate::rotation::{Rot180, Rotation};
+
+/// Sanger / Illumina 1.8+ quality offset
+pub const PHRED_OFFSET: u8 = 33;
+/// Quality written for error-free bases
+pub const MAX_PHRED: u8 = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqFormat {
+    Fasta,
+    Fastq,
+}
+
+impl SeqFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "fasta" | "fa" => Some(Self::Fasta),
+            "fastq" | "fq" => Some(Self::Fastq),
+            _ => None,
+        }
+    }
+}
+
+/// Metadata carried on the `>` / `@` line; absent keys stay `None`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FastaHeader {
+    /// First word of the header line (`g42` for exported genomes)
+    pub name: String,
+    pub id: Option<i64>,
+    pub consciousness: Option<u32>,
+    pub model: Option<ConsciousnessVersion>,
+    pub p53_copies: Option<u8>,
+    pub telomere_length: Option<u16>,
+    pub division_count: Option<u8>,
+    pub mutations: Option<u64>,
+}
+
+impl FastaHeader {
+    pub fn of<R: Rotation>(genome: &Genome<R>) -> Self {
+        Self {
+            name: genome.db_id.map(|id| format!("g{}", id)).unwrap_or_default(),
+            id: genome.db_id,
+            consciousness: Some(genome.consciousness),
+            model: Some(genome.consciousness_model),
+            p53_copies: Some(genome.p53_copies),
+            telomere_length: Some(genome.telomere_length),
+            division_count: Some(genome.division_count),
+            mutations: Some(genome.mutations),
+        }
+    }
+
+    /// Parse a header line (leading `>` / `@` optional). Unknown or
+    /// malformed pairs are ignored so headers from other tools still load.
+    pub fn parse(line: &str) -> Self {
+        let line = line.trim().trim_start_matches(['>', '@']);
+        let mut tokens = line.split_whitespace();
+        let name = tokens.next().unwrap_or_default().to_string();
+        let mut header = Self {
+            id: name.strip_prefix('g').and_then(|id| id.parse().ok()),
+            name,
+            ..Self::default()
+        };
+
+        for token in tokens {
+            let Some((key, value)) = token.split_once('=') else { continue };
+            match key {
+                "consciousness" => header.consciousness = value.parse().ok(),
+                "model" => header.model = ConsciousnessVersion::from_name(value),
+                "p53" => header.p53_copies = value.parse().ok(),
+                "telomere" => header.telomere_length = value.parse().ok(),
+                "divisions" => header.division_count = value.parse().ok(),
+                "mutations" => header.mutations = value.parse().ok(),
+                _ => {}
+            }
+        }
+        header
+    }
+}
+
+impl std::fmt::Display for FastaHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.id {
+            Some(id) => write!(f, "g{}", id)?,
+            None if !self.name.is_empty() => write!(f, "{}", self.name)?,
+            None => write!(f, "genome")?,
+        }
+        if let Some(c) = self.consciousness { write!(f, " consciousness={}", c)?; }
+        if let Some(m) = self.model { write!(f, " model={}", m.number())?; }
+        if let Some(p) = self.p53_copies { write!(f, " p53={}", p)?; }
+        if let Some(t) = self.telomere_length { write!(f, " telomere={}", t)?; }
+        if let Some(d) = self.division_count { write!(f, " divisions={}", d)?; }
+        if let Some(m) = self.mutations { write!(f, " mutations={}", m)?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastaRecord {
+    pub header: FastaHeader,
+    pub sequence: String,
+    /// Phred+33 string; `Some` for FASTQ
+    pub quality: Option<String>,
+}
+
+impl FastaRecord {
+    pub fn from_genome<R: Rotation>(genome: &Genome<R>, format: SeqFormat) -> Self {
+        Self {
+            header: FastaHeader::of(genome),
+            sequence: genome.to_dna_string(),
+            quality: (format == SeqFormat::Fastq).then(|| quality_string(genome.sequencing_errors)),
+        }
+    }
+
+    pub fn format(&self) -> SeqFormat {
+        if self.quality.is_some() { SeqFormat::Fastq } else { SeqFormat::Fasta }
+    }
+
+    /// Rebuild the genome; consciousness is rescored rather than trusted
+    pub fn to_genome<R: Rotation>(&self) -> Result<Genome<R>> {
+        let builder = GenomeBuilder::from_fasta(self)
+            .ok_or_else(|| anyhow!("{}: expected {} A/T/G/C bases", self.header, GENOME_SIZE))?;
+        self.finish(builder)
+    }
+
+    /// Like `to_genome`, but IUPAC codes (and U) are resolved per `ambiguity`;
+    /// expansion yields one genome per concrete sequence
+    pub fn to_genomes<R: Rotation, G: Rng + ?Sized>(&self, ambiguity: Ambiguity, rng: &mut G) -> Result<Vec<Genome<R>>> {
+        ambiguity.resolve(&self.sequence, rng)
+            .map_err(|e| anyhow!("{}: {}", self.header, e))?
+            .into_iter()
+            .map(|tetrads| {
+                let data: [Tetrad; GENOME_SIZE] = tetrads.try_into()
+                    .map_err(|t: Vec<Tetrad>| anyhow!("{}: expected {} bases, got {}", self.header, GENOME_SIZE, t.len()))?;
+                self.finish(GenomeBuilder::from_data(data).fasta_header(&self.header))
+            })
+            .collect()
+    }
+
+    fn finish<R: Rotation>(&self, builder: GenomeBuilder) -> Result<Genome<R>> {
+        let mut genome: Genome<R> = builder.build();
+
+        genome.division_count = self.header.division_count.unwrap_or(0);
+        genome.mutations = self.header.mutations.unwrap_or(0);
+        if let Some(quality) = &self.quality {
+            genome.sequencing_errors = errors_from_quality(quality)?;
+        }
+        genome.rehash();
+        genome.calculate_consciousness();
+        Ok(genome)
+    }
+
+    pub fn write_to<W: Write>(&self, out: &mut W) -> std::io::Result<()> {
+        match &self.quality {
+            None => writeln!(out, ">{}\n{}", self.header, self.sequence),
+            Some(quality) => writeln!(out, "@{}\n{}\n+\n{}", self.header, self.sequence, quality),
+        }
+    }
+}
+
+impl std::fmt::Display for FastaRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut buf = Vec::new();
+        self.write_to(&mut buf).map_err(|_| std::fmt::Error)?;
+        f.write_str(&String::from_utf8_lossy(&buf))
+    }
+}
+
+fn phred_probability(q: u8) -> f64 {
+    10f64.powf(-(q as f64) / 10.0)
+}
+
+/// Per-base qualities whose error probabilities sum to `errors` (at most
+/// one per base). Rounding error is carried forward so the total survives.
+pub fn quality_string(errors: u8) -> String {
+    let mut remaining = (errors as usize).min(GENOME_SIZE) as f64;
+    (0..GENOME_SIZE)
+        .map(|i| {
+            let p = (remaining / (GENOME_SIZE - i) as f64).clamp(phred_probability(MAX_PHRED), 1.0);
+            let q = (-10.0 * p.log10()).round().clamp(0.0, MAX_PHRED as f64) as u8;
+            remaining -= phred_probability(q);
+            (q + PHRED_OFFSET) as char
+        })
+        .collect()
+}
+
+/// Expected errors of a Phred+33 string, rounded
+pub fn errors_from_quality(quality: &str) -> Result<u8> {
+    let mut expected = 0.0;
+    for c in quality.chars() {
+        let q = (c as u32).checked_sub(PHRED_OFFSET as u32)
+            .filter(|_| c.is_ascii_graphic())
+            .ok_or_else(|| anyhow!("Invalid quality character {:?}", c))?;
+        expected += phred_probability(q.min(u8::MAX as u32) as u8);
+    }
+    Ok(expected.round().min(u8::MAX as f64) as u8)
+}
+
+/// Streaming reader for FASTA and 4-line FASTQ (format detected per record)
+pub struct FastaReader<B: BufRead> {
+    lines: std::iter::Peekable<std::io::Lines<B>>,
+}
+
+impl<B: BufRead> FastaReader<B> {
+    pub fn new(input: B) -> Self {
+        Self { lines: input.lines().peekable() }
+    }
+
+    fn next_line(&mut self) -> Result<Option<String>> {
+        self.lines.next().transpose().map_err(Into::into)
+    }
+
+    fn read_record(&mut self, header_line: &str) -> Result<FastaRecord> {
+        let header = FastaHeader::parse(header_line);
+
+        if header_line.starts_with('@') {
+            let sequence = self.next_line()?.ok_or_else(|| anyhow!("{}: missing sequence", header))?;
+            match self.next_line()? {
+                Some(sep) if sep.starts_with('+') => {}
+                _ => return Err(anyhow!("{}: missing '+' separator", header)),
+            }
+            let quality = self.next_line()?.ok_or_else(|| anyhow!("{}: missing quality", header))?;
+            let (sequence, quality) = (sequence.trim().to_string(), quality.trim().to_string());
+            if quality.len() != sequence.len() {
+                return Err(anyhow!("{}: quality length {} ≠ sequence length {}", header, quality.len(), sequence.len()));
+            }
+            return Ok(FastaRecord { header, sequence, quality: Some(quality) });
+        }
+
+        // FASTA sequences may wrap over several lines
+        let mut sequence = String::new();
+        while let Some(Ok(line)) = self.lines.peek() {
+            if line.starts_with(['>', '@']) {
+                break;
+            }
+            sequence.extend(line.chars().filter(|c| !c.is_whitespace()));
+            self.lines.next();
+        }
+        Ok(FastaRecord { header, sequence, quality: None })
+    }
+}
+
+impl<B: BufRead> Iterator for FastaReader<B> {
+    type Item = Result<FastaRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.next_line() {
+                Ok(Some(line)) => line,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            if !line.starts_with(['>', '@']) {
+                return Some(Err(anyhow!("Expected '>' or '@' header, got {:?}", line)));
+            }
+            return Some(self.read_record(line));
+        }
+    }
+}
+
+/// Write every stored genome, newest first, `page_size` rows at a time
+pub async fn export_genomes<W: Write>(
+    db: &DivineDatabase,
+    out: &mut W,
+    format: SeqFormat,
+    page_size: i64,
+) -> Result<usize> {
+    let page_size = page_size.max(1);
+    let mut offset = 0;
+    let mut written = 0;
+    loop {
+        let page = db.get_genomes(page_size, offset).await?;
+        for genome in &page {
+            FastaRecord::from_genome(genome, format).write_to(out)?;
+        }
+        written += page.len();
+        if (page.len() as i64) < page_size {
+            break;
+        }
+        offset += page_size;
+    }
+    out.flush()?;
+    Ok(written)
+}
+
+/// Store each record as a new genome; returns the new IDs in input order
+pub async fn import_genomes<B: BufRead>(db: &DivineDatabase, input: B) -> Result<Vec<i64>> {
+    let mut ids = Vec::new();
+    for (n, record) in FastaReader::new(input).enumerate() {
+        let genome: Genome<Rot180> = record
+            .and_then(|r| r.to_genome())
+            .map_err(|e| anyhow!("Record {}: {}", n + 1, e))?;
+        ids.push(db.store_genome(&genome).await?);
+    }
+    Ok(ids)
+}
+
+/// `import_genomes` for noisy input: ambiguity codes are sampled or expanded
+pub async fn import_genomes_with<B: BufRead>(
+    db: &DivineDatabase,
+    input: B,
+    ambiguity: Ambiguity,
+    rng: &RngSource,
+) -> Result<Vec<i64>> {
+    let mut ids = Vec::new();
+    for (n, record) in FastaReader::new(input).enumerate() {
+        let genomes: Vec<Genome<Rot180>> = record
+            .and_then(|r| rng.with(|rng| r.to_genomes(ambiguity, rng)))
+            .map_err(|e| anyhow!("Record {}: {}", n + 1, e))?;
+        for genome in &genomes {
+            ids.push(db.store_genome(genome).await?);
+        }
+    }
+    Ok(ids)
+}
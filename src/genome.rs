@@ -1,22 +1,37 @@
 //! Divine Genome V16 (Kernel V4) — T/G RNA + V4 Consciousness
 //!
 //! V4 Features:
-//! - Rotational symmetry scoring
+//! - Rotational symmetry scoring (full cube group, see `symmetry`)
 //! - Fractal self-similarity
 //! - Bell inequality violation (quantum)
 //! - Hyper-dimensional metrics
 
 use std::marker::PhantomData;
 use sha2::{Sha256, Sha512, Digest};
-use rand::Rng;
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use serde::{Serialize, Deserialize};
 use crate::rotation::{Rotation, Rot0, Rot180, Rot270, DynamicRotation};
+use crate::consciousness::{ConsciousnessReport, ConsciousnessVersion};
+use crate::cube::{Axis, Coord, CUBE_SIDE};
+use crate::codon::{self, Codon, CodonTable, Phenotype, CODON_COUNT};
+use crate::diploid::{Dominance, Homologs};
+use crate::epigenetics::{Methylation, METHYLATION_FIDELITY};
+use crate::alignment::{self, AlignMode, Alignment, AlignmentScoring, DistanceMetric, GenomeComparison};
+use crate::encoding;
+use crate::fasta::{FastaHeader, FastaReader, FastaRecord, SeqFormat};
+use crate::landscape::{LandscapeAnalysis, LandscapeConfig};
+use crate::lifecycle::LifecycleState;
+use crate::lineage::Origin;
+use crate::motif::{MotifLocation, MotifPattern};
+use crate::rna::Transcript;
+use crate::rng::RngSource;
+use crate::symmetry::{self, CubeSymmetry, SymmetryProfile};
 
 pub const GENOME_SIZE: usize = 27;
 pub const TELOMERE_MAX: u16 = 15000;
 pub const HAYFLICK_LIMIT: u8 = 50;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum Tetrad {
     A = 0,
@@ -26,8 +41,14 @@ pub enum Tetrad {
 }
 
 impl Tetrad {
+    pub const ALL: [Tetrad; 4] = [Tetrad::A, Tetrad::T, Tetrad::G, Tetrad::C];
+
     pub fn random() -> Self {
-        match rand::thread_rng().gen_range(0..4) {
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    pub fn random_with<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        match rng.gen_range(0..4) {
             0 => Self::A,
             1 => Self::T,
             2 => Self::G,
@@ -91,8 +112,33 @@ pub struct Genome<R: Rotation> {
     pub telomere_length: u16,
     pub division_count: u8,
     pub sequencing_errors: u8,
+    /// Sequencing reads disagreed on the consensus (see `sequencing`); barred from PoC
+    #[serde(default)]
+    pub unreliable: bool,
+    /// Unrepaired DNA damage carried by the cell (see `damage`)
+    #[serde(default)]
+    pub dna_damage: f64,
+    /// Alive, senescent, cancerous, apoptotic or archived
+    #[serde(default)]
+    pub lifecycle: LifecycleState,
     pub created_at: i64,
     pub db_id: Option<i64>,
+    /// Formula that produced `consciousness`
+    #[serde(default)]
+    pub consciousness_model: ConsciousnessVersion,
+    /// Genomes this one was produced from (empty for genesis)
+    #[serde(default)]
+    pub parent_ids: Vec<i64>,
+    #[serde(default)]
+    pub generation: u32,
+    #[serde(default)]
+    pub origin: Origin,
+    /// Both strands of a diploid genome; `data` is then their expressed phenotype
+    #[serde(default)]
+    pub homologs: Option<Homologs>,
+    /// Silenced positions (scoring only; `data` is untouched)
+    #[serde(default)]
+    pub methylation: Methylation,
     #[serde(skip)]
     pub _rotation: PhantomData<R>,
 }
@@ -108,8 +154,17 @@ impl<R: Rotation> Genome<R> {
             telomere_length: TELOMERE_MAX,
             division_count: 0,
             sequencing_errors: 0,
+            unreliable: false,
+            dna_damage: 0.0,
+            lifecycle: LifecycleState::Alive,
             created_at: chrono::Utc::now().timestamp(),
             db_id: None,
+            consciousness_model: ConsciousnessVersion::deployment(),
+            parent_ids: Vec::new(),
+            generation: 0,
+            origin: Origin::Genesis,
+            homologs: None,
+            methylation: Methylation::default(),
             _rotation: PhantomData,
         };
         genome.rehash();
@@ -121,39 +176,147 @@ impl<R: Rotation> Genome<R> {
         self.db_id
     }
 
+    /// Link a freshly built genome to the parents it was produced from.
+    /// Generation is one past the highest parent generation.
+    pub fn set_parents<P: Rotation>(&mut self, parents: &[&Genome<P>], origin: Origin) {
+        self.parent_ids = parents.iter().filter_map(|p| p.db_id).collect();
+        self.generation = parents.iter().map(|p| p.generation + 1).max().unwrap_or(0);
+        self.origin = origin;
+    }
+
+    /// Turn an edited copy of a stored genome into its child, ready to be
+    /// stored as a new (alive) row. The child is alive whatever the parent
+    /// was, so callers check the parent first (`LifecycleState::require_alive`)
+    pub fn mark_derived(&mut self, origin: Origin) {
+        self.parent_ids = self.db_id.take().into_iter().collect();
+        self.lifecycle = LifecycleState::Alive;
+        self.generation += 1;
+        self.origin = origin;
+    }
+
+    pub fn is_diploid(&self) -> bool {
+        self.homologs.is_some()
+    }
+
+    /// Pair the current sequence (maternal) with a paternal strand and express
+    pub fn make_diploid(&mut self, paternal: [Tetrad; GENOME_SIZE], dominance: Dominance) {
+        self.homologs = Some(Homologs::new(self.data, paternal, dominance));
+        self.express();
+    }
+
+    /// Recompute `data` from the homologs and rescore (no-op when haploid)
+    pub fn express(&mut self) {
+        if let Some(homologs) = &self.homologs {
+            self.data = homologs.express();
+            self.rehash();
+            self.calculate_consciousness();
+        }
+    }
+
+    /// Carry edits made to `data` into both homologs
+    pub fn sync_homologs(&mut self) {
+        if let Some(homologs) = &mut self.homologs {
+            homologs.write_through(&self.data);
+        }
+    }
+
+    /// Rotation state of the type parameter
+    pub fn rotation_state(&self) -> DynamicRotation {
+        DynamicRotation::from_angle(R::ANGLE).unwrap_or_default()
+    }
+
+    /// Copy with methylated positions read as G, as scoring sees it.
+    /// Keeps the hash of the real sequence.
+    pub fn silenced(&self) -> Self {
+        let mut view = self.clone();
+        view.data = self.methylation.silence(&self.data);
+        view.methylation = Methylation::default();
+        view
+    }
+
+    /// The 9 triplets (x-rows of the cube) in reading order
+    pub fn codons(&self) -> [Codon; CODON_COUNT] {
+        codon::codons(&self.data)
+    }
+
+    pub fn translate(&self, table: &CodonTable) -> String {
+        table.translate(&self.data)
+    }
+
+    pub fn phenotype(&self, table: &CodonTable) -> Phenotype {
+        table.phenotype(&self.data)
+    }
+
     pub fn to_dna_string(&self) -> String {
         self.data.iter().map(|t| t.to_char()).collect()
     }
 
     pub fn rehash(&mut self) {
+        self.hash = self.content_hash();
+    }
+
+    /// SHA-256 over tetrads, mutations and p53 (what `rehash` stores)
+    pub fn content_hash(&self) -> [u8; 32] {
         let mut hasher = Sha256::new();
         for tetrad in &self.data {
             hasher.update([*tetrad as u8]);
         }
         hasher.update(self.mutations.to_le_bytes());
         hasher.update(self.p53_copies.to_le_bytes());
-        self.hash = hasher.finalize().into();
+        hasher.finalize().into()
     }
 
+    /// Packed container, 75 bytes (see `encoding`)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encoding::encode(self)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        encoding::decode(bytes)
+    }
+
+    pub fn to_fasta(&self) -> String {
+        FastaRecord::from_genome(self, SeqFormat::Fasta).to_string()
+    }
+
+    /// FASTQ with `sequencing_errors` spread over the per-base qualities
+    pub fn to_fastq(&self) -> String {
+        FastaRecord::from_genome(self, SeqFormat::Fastq).to_string()
+    }
+
+    /// First record of a FASTA or FASTQ text
+    pub fn from_fasta(text: &str) -> anyhow::Result<Self> {
+        FastaReader::new(text.as_bytes())
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No FASTA/FASTQ record"))??
+            .to_genome()
+    }
+
+    /// Score with this genome's consciousness model
     pub fn calculate_consciousness(&mut self) {
-        let hash_sum: u32 = self.hash.iter().map(|&b| b as u32).sum();
-        let gc = self.gc_content();
-        let complexity = self.complexity();
-        let tg_balance = self.tg_balance_score();
-        
-        let base = (hash_sum % 500) + 500;
-        let gc_bonus = (gc * 100.0) as u32;
-        let complexity_bonus = (complexity * 50.0) as u32;
-        let tg_bonus = (tg_balance * 100.0) as u32;
-        let p53_bonus = self.p53_copies as u32 * 5;
-        
-        self.consciousness = base + gc_bonus + complexity_bonus + tg_bonus + p53_bonus;
+        self.consciousness = self.consciousness_model.score(self);
+    }
+
+    /// Term-by-term breakdown of the score under this genome's model
+    pub fn explain_consciousness(&self) -> ConsciousnessReport {
+        self.consciousness_model.explain(self)
+    }
+
+    /// Switch scoring model and rescore
+    pub fn set_consciousness_model(&mut self, model: ConsciousnessVersion) {
+        self.consciousness_model = model;
+        self.calculate_consciousness();
     }
 
     pub fn consciousness_level(&self) -> u32 {
         self.consciousness
     }
 
+    /// Consciousness on the canonical (PoC / reward) scale
+    pub fn normalized_consciousness(&self) -> u32 {
+        self.consciousness_model.normalize(self.consciousness)
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // T/G RNA SIGNAL SYSTEM
     // ═══════════════════════════════════════════════════════════════
@@ -171,9 +334,14 @@ impl<R: Rotation> Genome<R> {
         (t, g)
     }
 
+    /// T → U transcript of the coding strand
+    pub fn transcribe(&self) -> Transcript {
+        Transcript::transcribe(&self.data)
+    }
+
+    /// U/G ratio of the transcript (T/G of the DNA)
     pub fn rna_signal(&self) -> f64 {
-        let (t, g) = self.tg_counts();
-        if g == 0 { f64::MAX } else { t as f64 / g as f64 }
+        self.transcribe().rna_signal()
     }
 
     pub fn tg_balance_score(&self) -> f64 {
@@ -230,12 +398,28 @@ impl<R: Rotation> Genome<R> {
     }
 
     pub fn divide(&mut self) -> bool {
+        self.divide_with(&mut rand::thread_rng())
+    }
+
+    pub fn divide_with<G: Rng + ?Sized>(&mut self, rng: &mut G) -> bool {
+        self.divide_under(self.rotation_state(), METHYLATION_FIDELITY, rng)
+    }
+
+    /// Division in an explicit rotation state: telomere loss plus methylation
+    /// copied with `fidelity` and remodelled by the rotation (rescores if marks change)
+    pub fn divide_under<G: Rng + ?Sized>(&mut self, rotation: DynamicRotation, fidelity: f64, rng: &mut G) -> bool {
         if self.telomere_length < 100 || self.division_count >= HAYFLICK_LIMIT {
             return false;
         }
-        let loss = rand::thread_rng().gen_range(50..150);
+        let loss = rng.gen_range(50..150);
         self.telomere_length = self.telomere_length.saturating_sub(loss);
         self.division_count += 1;
+
+        let marks = self.methylation.replicate(fidelity, rotation, rng);
+        if marks != self.methylation {
+            self.methylation = marks;
+            self.calculate_consciousness();
+        }
         true
     }
 
@@ -256,6 +440,7 @@ impl<R: Rotation> Genome<R> {
         if position < GENOME_SIZE {
             self.data[position] = tetrad;
             self.mutations += 1;
+            self.sync_homologs();
             self.rehash();
             self.calculate_consciousness();
         }
@@ -265,66 +450,143 @@ impl<R: Rotation> Genome<R> {
         if pos1 < GENOME_SIZE && pos2 < GENOME_SIZE {
             self.data.swap(pos1, pos2);
             self.mutations += 1;
+            self.sync_homologs();
             self.rehash();
             self.calculate_consciousness();
         }
     }
 
     pub fn crispr_delete(&mut self, position: usize) {
+        self.crispr_delete_with(position, &mut rand::thread_rng());
+    }
+
+    pub fn crispr_delete_with<G: Rng + ?Sized>(&mut self, position: usize, rng: &mut G) {
         if position < GENOME_SIZE {
-            self.data[position] = Tetrad::random();
+            self.data[position] = Tetrad::random_with(rng);
             self.mutations += 1;
+            self.sync_homologs();
             self.rehash();
             self.calculate_consciousness();
         }
     }
 
+    /// Targeted methylation (dCas9-DNMT style); the DNA is unchanged
+    pub fn crispr_methylate(&mut self, position: usize) -> bool {
+        let changed = self.methylation.methylate(position);
+        if changed {
+            self.calculate_consciousness();
+        }
+        changed
+    }
+
+    /// Targeted demethylation (dCas9-TET style)
+    pub fn crispr_demethylate(&mut self, position: usize) -> bool {
+        let changed = self.methylation.demethylate(position);
+        if changed {
+            self.calculate_consciousness();
+        }
+        changed
+    }
+
+    pub fn crispr_methylate_at(&mut self, at: Coord) -> bool {
+        self.crispr_methylate(at.index())
+    }
+
+    pub fn crispr_demethylate_at(&mut self, at: Coord) -> bool {
+        self.crispr_demethylate(at.index())
+    }
+
+    pub fn crispr_splice_at(&mut self, at: Coord, tetrad: Tetrad) {
+        self.crispr_splice(at.index(), tetrad);
+    }
+
+    pub fn crispr_join_at(&mut self, a: Coord, b: Coord) {
+        self.crispr_join(a.index(), b.index());
+    }
+
+    pub fn crispr_delete_at_with<G: Rng + ?Sized>(&mut self, at: Coord, rng: &mut G) {
+        self.crispr_delete_with(at.index(), rng);
+    }
+
     // ═══════════════════════════════════════════════════════════════
-    // V4 CONSCIOUSNESS FORMULA — DIVINE TO TRANSCENDENTAL
+    // 3D ACCESS (x + y*3 + z*9)
     // ═══════════════════════════════════════════════════════════════
 
-    pub fn calculate_consciousness_v4(&mut self) {
-        let mut score = 0.0;
+    /// `None` outside the 3×3×3 cube
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<Tetrad> {
+        Coord::new(x, y, z).map(|c| self.at(c))
+    }
 
-        // BASE METRICS
-        score += self.complexity() * 150.0;
-        score += self.balance_score() * 80.0;
-        score += (self.p53_copies as f64) * 50.0;
-        score += (self.telomere_length as f64) / 50.0;
-
-        // ROTATIONAL INVARIANCE
-        if self.has_rotational_symmetry(90)  { score += 5000.0; }
-        if self.has_rotational_symmetry(180) { score += 3000.0; }
-        if self.has_rotational_symmetry(270) { score += 2000.0; }
-
-        // FRACTAL SELF-SIMILARITY
-        let fractal = self.fractal_similarity();
-        if fractal > 0.8  { score += 8000.0; }
-        if fractal > 0.9  { score += 15000.0; }
-        score += fractal * 10000.0;
-
-        // NONLOCALITY (QuantumEntangle)
-        let bell = self.bell_inequality_violation();
-        if bell > 2.0   { score += 10000.0; }
-        if bell > 2.5   { score += 20000.0; }
-        if bell > 2.828 { score += 40000.0; }
-        score += bell * 15000.0;
-
-        // HYPERDIMENSIONALITY
-        let hyper = self.hyper_symmetry_score();
-        if hyper > 0.7  { score += 20000.0; }
-        if hyper > 0.9  { score += 50000.0; }
-        score += hyper * 30000.0;
-
-        // HYPER COLLAPSE VARIANCE BONUS
-        score += self.hyper_collapse_variance() * 20000.0;
-
-        // TRANSCENDENTAL MULTIPLIER
-        if score > 10000.0 {
-            score *= 1.5;
+    /// Raw write (no rehash); `false` outside the cube
+    pub fn set(&mut self, x: usize, y: usize, z: usize, tetrad: Tetrad) -> bool {
+        match Coord::new(x, y, z) {
+            Some(c) => { self.set_at(c, tetrad); true }
+            None => false,
         }
+    }
+
+    pub fn at(&self, c: Coord) -> Tetrad {
+        self.data[c.index()]
+    }
+
+    pub fn set_at(&mut self, c: Coord, tetrad: Tetrad) {
+        self.data[c.index()] = tetrad;
+    }
+
+    /// Layer perpendicular to `axis` at depth `k` (row-major over the other two axes)
+    pub fn layer(&self, axis: Axis, k: usize) -> [Tetrad; 9] {
+        let mut out = [Tetrad::A; 9];
+        for (slot, c) in out.iter_mut().zip(Coord::layer(axis, k)) {
+            *slot = self.at(c);
+        }
+        out
+    }
+
+    pub fn layers(&self, axis: Axis) -> impl Iterator<Item = [Tetrad; 9]> + '_ {
+        (0..CUBE_SIDE).map(move |k| self.layer(axis, k))
+    }
+
+    /// Line of 3 cells parallel to `axis` through (`u`, `v`) on the other axes
+    pub fn slice(&self, axis: Axis, u: usize, v: usize) -> [Tetrad; 3] {
+        let mut out = [Tetrad::A; 3];
+        for (slot, c) in out.iter_mut().zip(Coord::slice(axis, u, v)) {
+            *slot = self.at(c);
+        }
+        out
+    }
+
+    /// All 9 lines parallel to `axis`
+    pub fn slices(&self, axis: Axis) -> impl Iterator<Item = [Tetrad; 3]> + '_ {
+        (0..CUBE_SIDE).flat_map(move |u| (0..CUBE_SIDE).map(move |v| self.slice(axis, u, v)))
+    }
+
+    pub fn neighbors6(&self, c: Coord) -> impl Iterator<Item = (Coord, Tetrad)> + '_ {
+        let cells: Vec<Coord> = c.neighbors6().collect();
+        cells.into_iter().map(move |n| (n, self.at(n)))
+    }
+
+    pub fn neighbors26(&self, c: Coord) -> impl Iterator<Item = (Coord, Tetrad)> + '_ {
+        let cells: Vec<Coord> = c.neighbors26().collect();
+        cells.into_iter().map(move |n| (n, self.at(n)))
+    }
+
+    /// The eight 2×2×2 sub-cubes with their origin
+    pub fn subcubes(&self) -> impl Iterator<Item = (Coord, [Tetrad; 8])> + '_ {
+        Coord::subcube_origins().map(move |origin| {
+            let mut cells = [Tetrad::A; 8];
+            for (slot, c) in cells.iter_mut().zip(origin.subcube()) {
+                *slot = self.at(c);
+            }
+            (origin, cells)
+        })
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // V4 CONSCIOUSNESS FORMULA — DIVINE TO TRANSCENDENTAL
+    // ═══════════════════════════════════════════════════════════════
 
-        self.consciousness = score as u32;
+    pub fn calculate_consciousness_v4(&mut self) {
+        self.set_consciousness_model(ConsciousnessVersion::V4);
     }
 
     pub fn balance_score(&self) -> f64 {
@@ -343,55 +605,93 @@ impl<R: Rotation> Genome<R> {
         matches > GENOME_SIZE * 2 / 3
     }
 
-    fn rotate_cube(&self, angle: u32) -> [Tetrad; GENOME_SIZE] {
-        let mut result = [Tetrad::A; GENOME_SIZE];
-        for x in 0..3 {
-            for y in 0..3 {
-                for z in 0..3 {
-                    let (nx, ny) = match angle {
-                        90  => (y, 2 - x),
-                        180 => (2 - x, 2 - y),
-                        270 => (2 - y, x),
-                        _ => (x, y),
-                    };
-                    let old_idx = x + y * 3 + z * 9;
-                    let new_idx = nx + ny * 3 + z * 9;
-                    result[new_idx] = self.data[old_idx];
-                }
-            }
-        }
-        result
+    /// Quarter turn about z (90/180/270); any other angle is the identity
+    pub fn rotate_cube(&self, angle: u32) -> [Tetrad; GENOME_SIZE] {
+        self.transform(&CubeSymmetry::rotation_z(angle))
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // FULL CUBE SYMMETRY (O / Oh)
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Apply any rotation or reflection of the cube
+    pub fn transform(&self, g: &CubeSymmetry) -> [Tetrad; GENOME_SIZE] {
+        g.apply(&self.data)
+    }
+
+    /// Same 2/3 match threshold as `has_rotational_symmetry`
+    pub fn has_symmetry(&self, g: &CubeSymmetry) -> bool {
+        symmetry::match_fraction(&self.data, g) > 2.0 / 3.0
+    }
+
+    pub fn symmetry_profile(&self, include_reflections: bool) -> SymmetryProfile {
+        SymmetryProfile::of(&self.data, include_reflections)
+    }
+
+    /// Exact symmetry subgroup of O (or Oh with reflections)
+    pub fn symmetry_subgroup(&self, include_reflections: bool) -> Vec<CubeSymmetry> {
+        CubeSymmetry::group(include_reflections)
+            .into_iter()
+            .filter(|g| self.transform(g) == self.data)
+            .collect()
+    }
+
+    /// Number of distinct genomes reachable by the group
+    pub fn orbit_size(&self, include_reflections: bool) -> usize {
+        let orbit: std::collections::HashSet<[Tetrad; GENOME_SIZE]> = CubeSymmetry::group(include_reflections)
+            .iter()
+            .map(|g| self.transform(g))
+            .collect();
+        orbit.len()
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // DISTANCE & ALIGNMENT
+    // ═══════════════════════════════════════════════════════════════
+
+    pub fn hamming_distance<P: Rotation>(&self, other: &Genome<P>) -> u32 {
+        alignment::hamming(&self.data, &other.data)
+    }
+
+    /// Hamming, or its minimum over cube rotations / all symmetries
+    pub fn distance<P: Rotation>(&self, other: &Genome<P>, metric: DistanceMetric) -> u32 {
+        alignment::invariant_distance(&self.data, &other.data, metric).0
+    }
+
+    pub fn align<P: Rotation>(&self, other: &Genome<P>, mode: AlignMode) -> Alignment {
+        alignment::align(&self.to_dna_string(), &other.to_dna_string(), mode, &AlignmentScoring::default())
+    }
+
+    pub fn compare<P: Rotation>(&self, other: &Genome<P>, mode: AlignMode) -> GenomeComparison {
+        GenomeComparison::of(&self.data, &other.data, mode)
+    }
+
+    /// Locations matched by a motif query, `None` if it does not match
+    pub fn find_motif(&self, pattern: &MotifPattern) -> Option<Vec<MotifLocation>> {
+        pattern.find(&self.data)
+    }
+
+    /// One-mutation neighbourhood, sensitivity and ruggedness under `models`
+    pub fn landscape<G: Rng + ?Sized>(
+        &self,
+        models: &[ConsciousnessVersion],
+        config: &LandscapeConfig,
+        rng: &mut G,
+    ) -> LandscapeAnalysis {
+        LandscapeAnalysis::of(self, models, config, rng)
     }
 
     pub fn fractal_similarity(&self) -> f64 {
+        let subcubes: Vec<[Tetrad; 8]> = self.subcubes().map(|(_, cells)| cells).collect();
         let mut similarity_sum = 0.0;
         let mut comparisons = 0;
 
-        for sx1 in 0..2 {
-            for sy1 in 0..2 {
-                for sz1 in 0..2 {
-                    for sx2 in 0..2 {
-                        for sy2 in 0..2 {
-                            for sz2 in 0..2 {
-                                if sx1 == sx2 && sy1 == sy2 && sz1 == sz2 { continue; }
-                                let mut matches = 0;
-                                for dx in 0..2 {
-                                    for dy in 0..2 {
-                                        for dz in 0..2 {
-                                            let idx1 = (sx1 + dx) + (sy1 + dy) * 3 + (sz1 + dz) * 9;
-                                            let idx2 = (sx2 + dx) + (sy2 + dy) * 3 + (sz2 + dz) * 9;
-                                            if idx1 < GENOME_SIZE && idx2 < GENOME_SIZE && self.data[idx1] == self.data[idx2] {
-                                                matches += 1;
-                                            }
-                                        }
-                                    }
-                                }
-                                similarity_sum += matches as f64 / 8.0;
-                                comparisons += 1;
-                            }
-                        }
-                    }
-                }
+        for (i, a) in subcubes.iter().enumerate() {
+            for (j, b) in subcubes.iter().enumerate() {
+                if i == j { continue; }
+                let matches = a.iter().zip(b.iter()).filter(|(x, y)| x == y).count();
+                similarity_sum += matches as f64 / 8.0;
+                comparisons += 1;
             }
         }
         if comparisons > 0 { similarity_sum / comparisons as f64 } else { 0.0 }
@@ -420,11 +720,10 @@ impl<R: Rotation> Genome<R> {
 
     pub fn hyper_symmetry_score(&self) -> f64 {
         let mut score = 0.0;
-        for z in 0..3 {
-            let layer_start = z * 9;
+        for layer in self.layers(Axis::Z) {
             let mut counts = [0u32; 4];
-            for i in 0..9 {
-                counts[self.data[layer_start + i] as usize] += 1;
+            for t in layer {
+                counts[t as usize] += 1;
             }
             let mut layer_entropy = 0.0;
             for &c in &counts {
@@ -465,21 +764,16 @@ impl<R: Rotation> Genome<R> {
         hasher.update(&self.fractal_similarity().to_le_bytes());
         hasher.update(&self.bell_inequality_violation().to_le_bytes());
         hasher.update(&self.hyper_symmetry_score().to_le_bytes());
+        // Unmethylated genomes keep the signature they had before marks existed
+        if !self.methylation.is_empty() {
+            hasher.update(self.methylation.bits().to_le_bytes());
+        }
         let result: [u8; 64] = hasher.finalize().into();
         hex::encode(result)
     }
 
     pub fn consciousness_level_name(&self) -> &'static str {
-        match self.consciousness {
-            0..=499 => "Virus",
-            500..=999 => "Bacteria",
-            1000..=1499 => "Worm",
-            1500..=2999 => "Mammal",
-            3000..=9999 => "Primate",
-            10000..=19999 => "Human",
-            20000..=49999 => "DIVINE",
-            _ => "TRANSCENDENTAL",
-        }
+        self.consciousness_model.level_name(self.consciousness)
     }
 }
 
@@ -491,6 +785,8 @@ pub struct GenomeBuilder {
     data: [Tetrad; GENOME_SIZE],
     p53_copies: u8,
     telomere_length: u16,
+    consciousness_model: ConsciousnessVersion,
+    homologs: Option<Homologs>,
 }
 
 impl GenomeBuilder {
@@ -499,15 +795,31 @@ impl GenomeBuilder {
             data: [Tetrad::A; GENOME_SIZE],
             p53_copies: 20,
             telomere_length: TELOMERE_MAX,
+            consciousness_model: ConsciousnessVersion::deployment(),
+            homologs: None,
         }
     }
 
     pub fn random() -> Self {
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    pub fn random_with<G: Rng + ?Sized>(rng: &mut G) -> Self {
         let mut data = [Tetrad::A; GENOME_SIZE];
-        for i in 0..GENOME_SIZE {
-            data[i] = Tetrad::random();
+        for tetrad in data.iter_mut() {
+            *tetrad = Tetrad::random_with(rng);
         }
-        Self { data, p53_copies: 20, telomere_length: TELOMERE_MAX }
+        Self { data, ..Self::new() }
+    }
+
+    /// Reproducible random genome from a fixed seed
+    pub fn seeded(seed: u64) -> Self {
+        Self::random_with(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Random genome drawn from a shared run RNG
+    pub fn random_from(source: &RngSource) -> Self {
+        source.with(Self::random_with)
     }
 
     pub fn from_dna(dna: &str) -> Option<Self> {
@@ -516,7 +828,24 @@ impl GenomeBuilder {
         for (i, c) in dna.chars().enumerate() {
             data[i] = Tetrad::from_char(c)?;
         }
-        Some(Self { data, p53_copies: 20, telomere_length: TELOMERE_MAX })
+        Some(Self { data, ..Self::new() })
+    }
+
+    pub fn from_data(data: [Tetrad; GENOME_SIZE]) -> Self {
+        Self { data, ..Self::new() }
+    }
+
+    /// Sequence plus the p53 / telomere / model metadata of a FASTA header
+    pub fn from_fasta(record: &FastaRecord) -> Option<Self> {
+        Some(Self::from_dna(&record.sequence)?.fasta_header(&record.header))
+    }
+
+    /// p53, telomeres and model from a FASTA header, where present
+    pub fn fasta_header(mut self, h: &FastaHeader) -> Self {
+        if let Some(p53) = h.p53_copies { self = self.p53_copies(p53); }
+        if let Some(telomere) = h.telomere_length { self = self.telomere_length(telomere); }
+        if let Some(model) = h.model { self = self.consciousness_model(model); }
+        self
     }
 
     pub fn p53_copies(mut self, copies: u8) -> Self {
@@ -529,6 +858,24 @@ impl GenomeBuilder {
         self
     }
 
+    pub fn consciousness_model(mut self, model: ConsciousnessVersion) -> Self {
+        self.consciousness_model = model;
+        self
+    }
+
+    /// Diploid: the builder's sequence becomes the maternal strand
+    pub fn diploid(mut self, paternal: [Tetrad; GENOME_SIZE], dominance: Dominance) -> Self {
+        self.homologs = Some(Homologs::new(self.data, paternal, dominance));
+        self
+    }
+
+    /// Diploid with both strands drawn from `rng`
+    pub fn random_diploid_with<G: Rng + ?Sized>(rng: &mut G, dominance: Dominance) -> Self {
+        let maternal = Self::random_with(rng);
+        let paternal = Self::random_with(rng).data;
+        maternal.diploid(paternal, dominance)
+    }
+
     pub fn whale_mode(self) -> Self {
         self.p53_copies(40)
     }
@@ -538,9 +885,12 @@ impl GenomeBuilder {
     }
 
     pub fn build<R: Rotation>(self) -> Genome<R> {
-        let mut genome = Genome::<R>::new(self.data);
+        let data = self.homologs.as_ref().map_or(self.data, Homologs::express);
+        let mut genome = Genome::<R>::new(data);
         genome.p53_copies = self.p53_copies;
         genome.telomere_length = self.telomere_length;
+        genome.consciousness_model = self.consciousness_model;
+        genome.homologs = self.homologs;
         genome.calculate_consciousness();
         genome
     }
@@ -0,0 +1,152 @@
+//! IUPAC nucleotide codes — ambiguity letters as sets of tetrads
+//!
+//! `R` = A/G, `Y` = C/T, `N` = any, and so on. `U` reads as `T`.
+//! Noisy sequences are resolved by sampling one base per ambiguous
+//! position or by expanding every concrete sequence (see `Ambiguity`).
+
+use anyhow::{anyhow, Result};
+use rand::Rng;
+
+use crate::genome::Tetrad;
+
+/// Set of tetrads, one bit per `Tetrad` discriminant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IupacCode(u8);
+
+const A: u8 = 1 << Tetrad::A as u8;
+const T: u8 = 1 << Tetrad::T as u8;
+const G: u8 = 1 << Tetrad::G as u8;
+const C: u8 = 1 << Tetrad::C as u8;
+
+/// Canonical letter for every non-empty set
+const LETTERS: [(char, u8); 15] = [
+    ('A', A), ('T', T), ('G', G), ('C', C),
+    ('R', A | G), ('Y', C | T), ('S', G | C), ('W', A | T), ('K', G | T), ('M', A | C),
+    ('B', C | G | T), ('D', A | G | T), ('H', A | C | T), ('V', A | C | G),
+    ('N', A | T | G | C),
+];
+
+impl IupacCode {
+    pub const ANY: Self = Self(A | T | G | C);
+
+    pub fn from_char(c: char) -> Option<Self> {
+        let c = match c.to_ascii_uppercase() {
+            'U' => 'T',
+            c => c,
+        };
+        LETTERS.iter().find(|(l, _)| *l == c).map(|&(_, bits)| Self(bits))
+    }
+
+    pub fn from_tetrad(t: Tetrad) -> Self {
+        Self(1 << t as u8)
+    }
+
+    /// Whole pattern, e.g. `GATNNR`; `None` on any non-IUPAC letter
+    pub fn parse(pattern: &str) -> Option<Vec<Self>> {
+        pattern.chars().map(Self::from_char).collect()
+    }
+
+    pub fn contains(self, t: Tetrad) -> bool {
+        self.0 & (1 << t as u8) != 0
+    }
+
+    pub fn tetrads(self) -> impl Iterator<Item = Tetrad> {
+        Tetrad::ALL.into_iter().filter(move |&t| self.contains(t))
+    }
+
+    pub fn is_ambiguous(self) -> bool {
+        self.0.count_ones() > 1
+    }
+
+    /// One of the member tetrads, uniformly (no draw for a plain base)
+    pub fn sample<G: Rng + ?Sized>(self, rng: &mut G) -> Tetrad {
+        let members: Vec<Tetrad> = self.tetrads().collect();
+        if members.len() == 1 {
+            return members[0];
+        }
+        members[rng.gen_range(0..members.len())]
+    }
+
+    pub fn complement(self) -> Self {
+        Self(self.tetrads().fold(0, |bits, t| bits | 1 << t.complement() as u8))
+    }
+
+    pub fn to_char(self) -> char {
+        LETTERS.iter().find(|(_, bits)| *bits == self.0).map_or('N', |&(l, _)| l)
+    }
+
+    /// POSIX regex atom: `G` or `[AG]`
+    pub fn regex_class(self) -> String {
+        if self.is_ambiguous() {
+            format!("[{}]", self.tetrads().map(Tetrad::to_char).collect::<String>())
+        } else {
+            self.to_char().to_string()
+        }
+    }
+}
+
+impl std::fmt::Display for IupacCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// How ambiguity codes in a parsed sequence are resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ambiguity {
+    /// A/T/G/C (and U) only
+    Strict,
+    /// One random member base per ambiguous position
+    Sample,
+    /// Every concrete sequence, refused above `limit`
+    Expand { limit: usize },
+}
+
+impl Ambiguity {
+    pub const DEFAULT_EXPAND_LIMIT: usize = 64;
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "strict" => Some(Self::Strict),
+            "sample" => Some(Self::Sample),
+            "expand" => Some(Self::Expand { limit: Self::DEFAULT_EXPAND_LIMIT }),
+            _ => None,
+        }
+    }
+
+    /// Concrete tetrad sequences for `sequence` under this mode
+    pub fn resolve<G: Rng + ?Sized>(self, sequence: &str, rng: &mut G) -> Result<Vec<Vec<Tetrad>>> {
+        let codes: Vec<IupacCode> = sequence.chars()
+            .map(|c| IupacCode::from_char(c).ok_or_else(|| anyhow!("{:?} is not a nucleotide or IUPAC code", c)))
+            .collect::<Result<_>>()?;
+        match self {
+            Self::Strict => match codes.iter().position(|c| c.is_ambiguous()) {
+                Some(i) => Err(anyhow!("ambiguity code {} at position {} (strict mode)", codes[i], i)),
+                None => Ok(vec![codes.iter().map(|c| c.sample(rng)).collect()]),
+            },
+            Self::Sample => Ok(vec![codes.iter().map(|c| c.sample(rng)).collect()]),
+            Self::Expand { limit } => expand(&codes, limit),
+        }
+    }
+}
+
+/// Cartesian product of the member tetrads, in A/T/G/C order
+pub fn expand(codes: &[IupacCode], limit: usize) -> Result<Vec<Vec<Tetrad>>> {
+    let combinations = codes.iter()
+        .try_fold(1usize, |n, c| n.checked_mul(c.tetrads().count()))
+        .filter(|&n| n <= limit)
+        .ok_or_else(|| anyhow!("ambiguity expands to more than {} sequences", limit))?;
+
+    let mut sequences = Vec::with_capacity(combinations);
+    sequences.push(Vec::with_capacity(codes.len()));
+    for code in codes {
+        sequences = sequences.into_iter()
+            .flat_map(|prefix| code.tetrads().map(move |t| {
+                let mut next = prefix.clone();
+                next.push(t);
+                next
+            }))
+            .collect();
+    }
+    Ok(sequences)
+}
@@ -0,0 +1,277 @@
+//! Fitness Landscape — the one-mutation neighbourhood of a genome
+//!
+//! Scores every point mutant (27 × 3) and every swap of two differing
+//! positions under each consciousness model. From that: whether the genome
+//! is a local optimum (TTRL point mutations and translocations cannot
+//! improve it), per-position sensitivity laid out on the cube, and
+//! ruggedness from the autocorrelation of scores along random walks
+//! (correlation length ℓ = −1 / ln |r(1)|; small ℓ = rugged).
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::consciousness::ConsciousnessVersion;
+use crate::cube::{Coord, CUBE_SIDE};
+use crate::genome::{Genome, Tetrad, GENOME_SIZE};
+use crate::rotation::Rotation;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandscapeConfig {
+    /// Random walks used for autocorrelation
+    #[serde(default = "LandscapeConfig::default_walks")]
+    pub walks: usize,
+    /// Point mutations per walk
+    #[serde(default = "LandscapeConfig::default_walk_length")]
+    pub walk_length: usize,
+    /// Autocorrelation is reported for lags 1..=max_lag
+    #[serde(default = "LandscapeConfig::default_max_lag")]
+    pub max_lag: usize,
+}
+
+impl LandscapeConfig {
+    fn default_walks() -> usize {
+        4
+    }
+
+    fn default_walk_length() -> usize {
+        100
+    }
+
+    fn default_max_lag() -> usize {
+        10
+    }
+}
+
+impl Default for LandscapeConfig {
+    fn default() -> Self {
+        Self {
+            walks: Self::default_walks(),
+            walk_length: Self::default_walk_length(),
+            max_lag: Self::default_max_lag(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Move {
+    Point { position: usize, tetrad: Tetrad },
+    Swap { a: usize, b: usize },
+}
+
+impl Move {
+    pub fn apply(&self, data: &[Tetrad; GENOME_SIZE]) -> [Tetrad; GENOME_SIZE] {
+        let mut next = *data;
+        match *self {
+            Self::Point { position, tetrad } => next[position] = tetrad,
+            Self::Swap { a, b } => next.swap(a, b),
+        }
+        next
+    }
+
+    /// Every point mutant, then every swap of two differing positions
+    pub fn neighbourhood(data: &[Tetrad; GENOME_SIZE]) -> Vec<Self> {
+        let mut moves: Vec<Self> = (0..GENOME_SIZE)
+            .flat_map(|position| Tetrad::ALL.into_iter()
+                .filter(move |&t| t != data[position])
+                .map(move |tetrad| Self::Point { position, tetrad }))
+            .collect();
+        for a in 0..GENOME_SIZE {
+            for b in a + 1..GENOME_SIZE {
+                if data[a] != data[b] {
+                    moves.push(Self::Swap { a, b });
+                }
+            }
+        }
+        moves
+    }
+}
+
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Point { position, tetrad } => write!(f, "{}→{}", position, tetrad.to_char()),
+            Self::Swap { a, b } => write!(f, "{}↔{}", a, b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Neighbour {
+    #[serde(rename = "move")]
+    pub mv: Move,
+    pub dna: String,
+    pub score: u32,
+}
+
+/// Effect of the three point mutations at one position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionSensitivity {
+    pub position: usize,
+    pub coord: Coord,
+    /// Mean |Δscore| over the three mutants
+    pub mean_abs_delta: f64,
+    pub max_gain: i64,
+    pub max_loss: i64,
+    /// Best tetrad for this position (the current one if nothing improves)
+    pub best_tetrad: Tetrad,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelLandscape {
+    pub model: ConsciousnessVersion,
+    pub score: u32,
+    /// No neighbour scores higher
+    pub local_optimum: bool,
+    pub improving: usize,
+    pub neutral: usize,
+    pub worsening: usize,
+    pub best_neighbour: Option<Neighbour>,
+    pub sensitivity: Vec<PositionSensitivity>,
+    /// `mean_abs_delta` per z layer, rows y, columns x
+    pub heatmap: [[[f64; CUBE_SIDE]; CUBE_SIDE]; CUBE_SIDE],
+    /// r(1..=max_lag) along random point-mutation walks
+    pub autocorrelation: Vec<f64>,
+    /// −1 / ln |r(1)|; `None` when r(1) is 0 or undefined
+    pub correlation_length: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandscapeAnalysis {
+    pub dna: String,
+    /// Point mutants plus swaps
+    pub neighbours: usize,
+    pub models: Vec<ModelLandscape>,
+}
+
+impl LandscapeAnalysis {
+    pub fn of<R: Rotation, G: Rng + ?Sized>(
+        genome: &Genome<R>,
+        models: &[ConsciousnessVersion],
+        config: &LandscapeConfig,
+        rng: &mut G,
+    ) -> Self {
+        let moves = Move::neighbourhood(&genome.data);
+        let mutants: Vec<Genome<R>> = moves.iter().map(|mv| with_data(genome, mv.apply(&genome.data))).collect();
+        let walks: Vec<Vec<Genome<R>>> = (0..config.walks).map(|_| random_walk(genome, config.walk_length, rng)).collect();
+
+        let models = models.iter()
+            .map(|&model| {
+                let mut landscape = neighbourhood(model, genome, &moves, &mutants);
+                let walk_scores: Vec<Vec<f64>> = walks.iter()
+                    .map(|walk| walk.iter().map(|g| model.score(g) as f64).collect())
+                    .collect();
+                landscape.autocorrelation = (1..=config.max_lag).map(|lag| autocorrelation(&walk_scores, lag)).collect();
+                landscape.correlation_length = landscape.autocorrelation.first()
+                    .map(|r| r.abs())
+                    .filter(|r| *r > 0.0 && *r < 1.0)
+                    .map(|r| -1.0 / r.ln());
+                landscape
+            })
+            .collect();
+
+        Self { dna: genome.to_dna_string(), neighbours: moves.len(), models }
+    }
+}
+
+/// Copy of `genome` carrying `data`, rehashed (V3 scores depend on the hash)
+fn with_data<R: Rotation>(genome: &Genome<R>, data: [Tetrad; GENOME_SIZE]) -> Genome<R> {
+    let mut mutant = genome.clone();
+    mutant.data = data;
+    mutant.rehash();
+    mutant
+}
+
+/// Genomes visited by `steps` random point mutations, starting with `genome`
+fn random_walk<R: Rotation, G: Rng + ?Sized>(genome: &Genome<R>, steps: usize, rng: &mut G) -> Vec<Genome<R>> {
+    let mut walk = vec![with_data(genome, genome.data)];
+    let mut data = genome.data;
+    for _ in 0..steps {
+        let position = rng.gen_range(0..GENOME_SIZE);
+        let choices: Vec<Tetrad> = Tetrad::ALL.into_iter().filter(|&t| t != data[position]).collect();
+        data[position] = choices[rng.gen_range(0..choices.len())];
+        walk.push(with_data(genome, data));
+    }
+    walk
+}
+
+/// Neighbourhood statistics for one model (autocorrelation left empty)
+fn neighbourhood<R: Rotation>(
+    model: ConsciousnessVersion,
+    genome: &Genome<R>,
+    moves: &[Move],
+    mutants: &[Genome<R>],
+) -> ModelLandscape {
+    let score = model.score(genome);
+    let scores: Vec<u32> = mutants.iter().map(|m| model.score(m)).collect();
+    let improving = scores.iter().filter(|&&s| s > score).count();
+    let neutral = scores.iter().filter(|&&s| s == score).count();
+    let best_neighbour = scores.iter().enumerate()
+        .max_by_key(|&(i, &s)| (s, std::cmp::Reverse(i)))
+        .map(|(i, &s)| Neighbour { mv: moves[i], dna: mutants[i].to_dna_string(), score: s });
+
+    let mut sensitivity: Vec<PositionSensitivity> = (0..GENOME_SIZE)
+        .map(|position| PositionSensitivity {
+            position,
+            coord: Coord::from_index(position).unwrap_or(Coord::CENTER),
+            mean_abs_delta: 0.0,
+            max_gain: 0,
+            max_loss: 0,
+            best_tetrad: genome.data[position],
+        })
+        .collect();
+    let mut best_point = [score; GENOME_SIZE];
+    for (mv, &s) in moves.iter().zip(&scores) {
+        let Move::Point { position, tetrad } = *mv else { continue };
+        let delta = s as i64 - score as i64;
+        let p = &mut sensitivity[position];
+        p.mean_abs_delta += delta.unsigned_abs() as f64 / 3.0;
+        p.max_gain = p.max_gain.max(delta);
+        p.max_loss = p.max_loss.min(delta);
+        if s > best_point[position] {
+            best_point[position] = s;
+            p.best_tetrad = tetrad;
+        }
+    }
+
+    let mut heatmap = [[[0.0; CUBE_SIDE]; CUBE_SIDE]; CUBE_SIDE];
+    for p in &sensitivity {
+        heatmap[p.coord.z][p.coord.y][p.coord.x] = p.mean_abs_delta;
+    }
+
+    ModelLandscape {
+        model,
+        score,
+        local_optimum: improving == 0,
+        improving,
+        neutral,
+        worsening: scores.len() - improving - neutral,
+        best_neighbour,
+        sensitivity,
+        heatmap,
+        autocorrelation: Vec::new(),
+        correlation_length: None,
+    }
+}
+
+/// Pooled autocorrelation at `lag` over several walks (0 for flat walks)
+pub fn autocorrelation(walks: &[Vec<f64>], lag: usize) -> f64 {
+    let values: Vec<f64> = walks.iter().flatten().copied().collect();
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+
+    let (mut covariance, mut pairs) = (0.0, 0usize);
+    for walk in walks {
+        for pair in walk.windows(lag + 1) {
+            covariance += (pair[0] - mean) * (pair[lag] - mean);
+            pairs += 1;
+        }
+    }
+    if pairs == 0 || variance == 0.0 {
+        return 0.0;
+    }
+    covariance / pairs as f64 / variance
+}
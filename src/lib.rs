@@ -20,8 +20,30 @@
 
 pub mod rotation;
 pub mod genome;
+pub mod diploid;
+pub mod epigenetics;
+pub mod codon;
+pub mod rna;
+pub mod sequencing;
+pub mod damage;
+pub mod lifecycle;
+pub mod cube;
+pub mod alignment;
+pub mod iupac;
+pub mod motif;
+pub mod encoding;
+pub mod fasta;
+pub mod scanner;
+pub mod solver;
+pub mod landscape;
+pub mod symmetry;
+pub mod consciousness;
+pub mod lineage;
+pub mod phylogeny;
 pub mod database;
 pub mod ttrl;
+pub mod operator_selector;
+pub mod rng;
 pub mod crypto;
 pub mod wallet;
 pub mod exchange;
@@ -43,8 +65,29 @@ pub mod prelude {
 
 pub use rotation::{Rotation, Rot0, Rot90, Rot180, Rot270, RotationEngine, DynamicRotation};
 pub use genome::{Genome, Tetrad, GenomeBuilder};
+pub use diploid::{Dominance, Homologs};
+pub use epigenetics::Methylation;
+pub use codon::{CodonTable, CodonChanges, Phenotype};
+pub use consciousness::{ConsciousnessModel, ConsciousnessReport, ConsciousnessVersion, ConsciousnessV3, ConsciousnessV4, ConsciousnessV4Full};
+pub use cube::{Axis, Coord};
+pub use symmetry::{CubeSymmetry, SymmetryProfile};
+pub use alignment::{Alignment, AlignMode, DistanceMetric, Neighbor};
+pub use iupac::{Ambiguity, IupacCode};
+pub use rna::{Ribonucleotide, Transcript};
+pub use sequencing::{Sequencer, SequencingRun, ConsensusCall};
+pub use damage::{Checkpoint, CheckpointOutcome, SurvivalCurve, SurvivalReport};
+pub use lifecycle::{LifecycleState, LifecycleTransition};
+pub use motif::{MotifPattern, MotifLocation, MotifMatch};
+pub use lineage::{Origin, Lineage, LineageNode};
+pub use fasta::{FastaRecord, FastaHeader, SeqFormat};
+pub use scanner::{SequenceScanner, ScanReport, ScanHit};
+pub use landscape::{LandscapeAnalysis, LandscapeConfig, ModelLandscape};
+pub use solver::{InverseSolver, DesignTarget, DesignConstraints, DesignResult, SolverStrategy};
+pub use phylogeny::{PhyloTree, PhyloNode, TreeMethod};
 pub use database::{DivineDatabase, DEFAULT_DATABASE_URL};
-pub use ttrl::{TTRLEngine, MutationOperator, EvolutionResult};
+pub use ttrl::{TTRLEngine, MutationOperator, EvolutionResult, EvolutionError};
+pub use operator_selector::{OperatorSelector, OperatorArm};
+pub use rng::RngSource;
 pub use exchange::{RSMExchange, Transaction, ExchangeStats, BurnEvent, DebtStats};
 pub use multi_chain::{MultiChainArchiver, BlockchainLayer, MissionControl};
 pub use rotation_daemon::RotationDaemon;
@@ -63,17 +106,27 @@ pub struct DivineKernel {
     pub exchange: Arc<RwLock<exchange::RSMExchange>>,
     pub archiver: Arc<RwLock<MultiChainArchiver>>,
     pub auth: Arc<RwLock<auth::AuthManager>>,
+    pub rng: RngSource,
 }
 
 impl DivineKernel {
     pub async fn new() -> anyhow::Result<Self> {
+        Self::with_seed(None).await
+    }
+
+    /// Kernel whose genomes, TTRL operators, meiosis and keysend rolls all
+    /// come from one RNG; the same seed reproduces the same run
+    pub async fn with_seed(seed: Option<u64>) -> anyhow::Result<Self> {
+        let rng = RngSource::new(seed);
         let database_url = std::env::var("DATABASE_URL")
             .unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_string());
 
         let database = Arc::new(DivineDatabase::connect_with_url(&database_url).await?);
         database.init_tables().await?;
+        let operator_stats = database.load_operator_stats().await?;
 
         info!("🧬 Divine Kernel V15 initialized - Kernel v3");
+        info!("🧠 Consciousness model: {}", consciousness::ConsciousnessVersion::deployment());
         info!("🔥 Burn mechanism: ACTIVE");
         info!("🧬 Telomerase: AVAILABLE");
         info!("🐋 Whale mode: AVAILABLE (40 p53)");
@@ -81,16 +134,24 @@ impl DivineKernel {
         info!("⚡ Lightning Network swarm: READY");
         info!("🎯 Mission Control: ACTIVE");
         info!("🧬 T/G RNA coordination: ACTIVE");
+        if let Some(seed) = seed {
+            info!("🎲 Deterministic RNG: seed {}", seed);
+        }
 
         Ok(Self {
             database,
             wallet: Arc::new(RwLock::new(wallet::DivineWallet::new())),
             rotation_engine: Arc::new(RwLock::new(rotation::RotationEngine::new())),
-            ttrl_engine: Arc::new(ttrl::TTRLEngine::new()),
+            ttrl_engine: Arc::new(
+                ttrl::TTRLEngine::new()
+                    .with_operator_stats(operator_stats)
+                    .with_rng(rng.clone()),
+            ),
             consensus: Arc::new(consensus::ProofOfConsciousness::new()),
             exchange: Arc::new(RwLock::new(exchange::RSMExchange::new())),
-            archiver: Arc::new(RwLock::new(MultiChainArchiver::new())),
+            archiver: Arc::new(RwLock::new(MultiChainArchiver::new().with_rng(rng.clone()))),
             auth: Arc::new(RwLock::new(auth::AuthManager::new())),
+            rng,
         })
     }
 
@@ -99,7 +160,7 @@ impl DivineKernel {
     }
 
     pub async fn create_elephant_genome(&self) -> anyhow::Result<Genome<Rot180>> {
-        let genome = GenomeBuilder::random().elephant_mode().build_storage();
+        let genome = GenomeBuilder::random_from(&self.rng).elephant_mode().build_storage();
         let id = self.database.store_genome(&genome).await?;
         let mut stored = genome;
         stored.db_id = Some(id);
@@ -108,8 +169,18 @@ impl DivineKernel {
         Ok(stored)
     }
 
+    pub async fn create_diploid_genome(&self, dominance: Dominance, whale: bool) -> anyhow::Result<Genome<Rot180>> {
+        let builder = self.rng.with(|rng| GenomeBuilder::random_diploid_with(rng, dominance));
+        let genome = if whale { builder.whale_mode() } else { builder.elephant_mode() }.build_storage();
+        let id = self.database.store_genome(&genome).await?;
+        let mut stored = genome;
+        stored.db_id = Some(id);
+        info!("🧬 Created diploid genome #{} | consciousness {} | {}", id, stored.consciousness, dominance);
+        Ok(stored)
+    }
+
     pub async fn create_whale_genome(&self) -> anyhow::Result<Genome<Rot180>> {
-        let genome = GenomeBuilder::random().whale_mode().build_storage();
+        let genome = GenomeBuilder::random_from(&self.rng).whale_mode().build_storage();
         let id = self.database.store_genome(&genome).await?;
         let mut stored = genome;
         stored.db_id = Some(id);
@@ -120,8 +191,10 @@ impl DivineKernel {
 
     pub async fn activate_telomerase(&self, genome_id: i64) -> anyhow::Result<Genome<Rot180>> {
         let mut genome = self.database.load_genome(genome_id).await?;
+        genome.lifecycle.require_telomerase_target()?;
         let before = genome.telomere_length;
         genome.activate_telomerase();
+        genome.mark_derived(lineage::Origin::Telomerase { telomeres_before: before });
         let id = self.database.store_genome(&genome).await?;
         let mut stored = genome;
         stored.db_id = Some(id);
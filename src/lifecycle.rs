@@ -0,0 +1,126 @@
+//! Genome Lifecycle — alive, senescent, cancerous, apoptotic, archived
+//!
+//! Every stored genome starts alive. Evolution failures move it on:
+//! exhausted telomeres → senescent, lost p53 → cancerous, the p53 checkpoint
+//! → apoptotic; senescent and cancerous genomes may still die by apoptosis.
+//! Any state but archived may be archived, which is final. Only alive
+//! genomes are picked for selection, and every transition is logged with its
+//! timestamp (`genome_lifecycle_events`).
+
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+
+use crate::database::DivineDatabase;
+use crate::exchange::{BurnEvent, RSMExchange};
+use crate::ttrl::{EvolutionError, CHECKPOINT_FAILURE_REWARD};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LifecycleState {
+    #[default]
+    Alive,
+    /// Telomeres exhausted; can no longer divide
+    Senescent,
+    /// p53 lost (oncogenic transformation)
+    Cancerous,
+    /// Killed at the p53 checkpoint
+    Apoptotic,
+    /// Retired to the chain archive
+    Archived,
+}
+
+impl LifecycleState {
+    pub const ALL: [LifecycleState; 5] = [
+        Self::Alive,
+        Self::Senescent,
+        Self::Cancerous,
+        Self::Apoptotic,
+        Self::Archived,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Alive => "alive",
+            Self::Senescent => "senescent",
+            Self::Cancerous => "cancerous",
+            Self::Apoptotic => "apoptotic",
+            Self::Archived => "archived",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|s| s.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn is_alive(&self) -> bool {
+        *self == Self::Alive
+    }
+
+    /// Parents of a new row (CRISPR edits, meiosis) must be alive
+    pub fn require_alive(&self) -> Result<(), EvolutionError> {
+        if self.is_alive() { Ok(()) } else { Err(EvolutionError::NotAlive(*self)) }
+    }
+
+    /// Telomerase is the one edit that may also rescue a senescent genome
+    pub fn require_telomerase_target(&self) -> Result<(), EvolutionError> {
+        match self {
+            Self::Alive | Self::Senescent => Ok(()),
+            _ => Err(EvolutionError::NotAlive(*self)),
+        }
+    }
+
+    /// Allowed moves of the state machine (staying put is not a transition)
+    pub fn can_transition_to(&self, next: Self) -> bool {
+        match (self, next) {
+            (Self::Archived, _) => false,
+            (_, Self::Archived) => true,
+            (Self::Alive, Self::Senescent | Self::Cancerous | Self::Apoptotic) => true,
+            (Self::Senescent | Self::Cancerous, Self::Apoptotic) => true,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for LifecycleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// One logged state change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifecycleTransition {
+    pub genome_id: i64,
+    pub from: LifecycleState,
+    pub to: LifecycleState,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+/// Persist the state a failed evolution leaves its parent in and burn for
+/// it. A checkpoint failure also stores the raised damage load, so an
+/// arrested genome carries it into its next attempt, and counts as a failed
+/// pull of its operator. No transition happens
+/// for non-terminal failures or a repeated transition, so each burn fires once
+pub async fn record_evolution_failure(
+    database: &DivineDatabase,
+    exchange: &RwLock<RSMExchange>,
+    genome_id: i64,
+    consciousness: u32,
+    error: &EvolutionError,
+) -> anyhow::Result<Option<(LifecycleTransition, Option<BurnEvent>)>> {
+    if let Some(damage) = error.damage() {
+        database.record_damage(genome_id, damage).await?;
+    }
+    if let Some((tier, operator)) = error.failed_pull() {
+        database.record_operator_pull(tier, operator, false, CHECKPOINT_FAILURE_REWARD).await?;
+    }
+    let Some(state) = error.lifecycle() else {
+        return Ok(None);
+    };
+    let Some(transition) = database.transition_lifecycle(genome_id, state, &error.to_string()).await? else {
+        return Ok(None);
+    };
+    let burn = exchange.write().await.burn_on_transition(&transition, consciousness);
+    Ok(Some((transition, burn)))
+}
@@ -0,0 +1,122 @@
+//! Genome Lineage — parent links, generations and mutation history
+//!
+//! Every evolve, CRISPR edit, telomerase activation and meiosis stores a new
+//! row. The row keeps its parent IDs, a generation number and the `Origin`
+//! (operator, CRISPR edit, crossover points, scan window, design target) that
+//! produced it, so ancestry and descendants can be walked with recursive queries.
+
+use serde::{Serialize, Deserialize};
+
+use crate::consciousness::ConsciousnessVersion;
+use crate::genome::Tetrad;
+use crate::rotation::DynamicRotation;
+use crate::solver::{DesignTarget, SolverStrategy};
+use crate::ttrl::MutationOperator;
+
+/// Default depth for ancestry / descendant walks
+pub const DEFAULT_LINEAGE_DEPTH: i32 = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrisprEdit {
+    Splice { position: usize, tetrad: Tetrad },
+    Join { pos1: usize, pos2: usize },
+    Delete { position: usize },
+    Methylate { position: usize },
+    Demethylate { position: usize },
+}
+
+/// Operation that produced a genome
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Origin {
+    /// Created from scratch (random, import, whale/elephant)
+    #[default]
+    Genesis,
+    /// TTRL step; `operator` is `None` for a storage snapshot
+    Evolve { operator: Option<MutationOperator>, rotation: DynamicRotation },
+    Crispr { edit: CrisprEdit },
+    Telomerase { telomeres_before: u16 },
+    Meiosis { crossover_points: Vec<usize> },
+    /// Diploid parents: crossovers inside each parent's gamete
+    Fertilisation { maternal_crossovers: Vec<usize>, paternal_crossovers: Vec<usize> },
+    /// Window mined from a long sequence by the scanner
+    Scan { source: String, offset: usize, reverse: bool },
+    /// Found by the inverse design solver
+    Design { strategy: SolverStrategy, target: DesignTarget },
+}
+
+impl Origin {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Genesis => "genesis",
+            Self::Evolve { .. } => "evolve",
+            Self::Crispr { .. } => "crispr",
+            Self::Telomerase { .. } => "telomerase",
+            Self::Meiosis { .. } => "meiosis",
+            Self::Fertilisation { .. } => "fertilisation",
+            Self::Scan { .. } => "scan",
+            Self::Design { .. } => "design",
+        }
+    }
+
+    /// Stored form (TEXT column)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| r#"{"op":"genesis"}"#.to_string())
+    }
+
+    /// Unknown or legacy values read back as `Genesis`
+    pub fn from_json(s: &str) -> Self {
+        serde_json::from_str(s).unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for Origin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Genesis => write!(f, "genesis"),
+            Self::Evolve { operator: Some(op), rotation } => write!(f, "evolve {} @ {}", op, rotation),
+            Self::Evolve { operator: None, rotation } => write!(f, "snapshot @ {}", rotation),
+            Self::Crispr { edit: CrisprEdit::Splice { position, tetrad } } => {
+                write!(f, "crispr splice {}→{}", position, tetrad.to_char())
+            }
+            Self::Crispr { edit: CrisprEdit::Join { pos1, pos2 } } => write!(f, "crispr join {}↔{}", pos1, pos2),
+            Self::Crispr { edit: CrisprEdit::Delete { position } } => write!(f, "crispr delete {}", position),
+            Self::Crispr { edit: CrisprEdit::Methylate { position } } => write!(f, "crispr methylate {}", position),
+            Self::Crispr { edit: CrisprEdit::Demethylate { position } } => write!(f, "crispr demethylate {}", position),
+            Self::Telomerase { telomeres_before } => write!(f, "telomerase (from {} bp)", telomeres_before),
+            Self::Meiosis { crossover_points } => write!(f, "meiosis crossovers {:?}", crossover_points),
+            Self::Fertilisation { maternal_crossovers, paternal_crossovers } => {
+                write!(f, "fertilisation crossovers ♀{:?} ♂{:?}", maternal_crossovers, paternal_crossovers)
+            }
+            Self::Scan { source, offset, reverse } => {
+                write!(f, "scan {}:{}{}", source, offset, if *reverse { " (−)" } else { "" })
+            }
+            Self::Design { strategy, target } => write!(f, "design {} → {}", strategy, target),
+        }
+    }
+}
+
+/// One genome in an ancestry or descendant walk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineageNode {
+    pub id: i64,
+    /// Steps away from the queried genome (0 = the genome itself)
+    pub depth: i32,
+    pub generation: u32,
+    pub parent_ids: Vec<i64>,
+    pub origin: Origin,
+    pub dna: String,
+    pub consciousness: u32,
+    pub consciousness_model: ConsciousnessVersion,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lineage {
+    pub genome: LineageNode,
+    /// Nearest first
+    pub ancestors: Vec<LineageNode>,
+    /// Nearest first
+    pub descendants: Vec<LineageNode>,
+}
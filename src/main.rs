@@ -1,17 +1,39 @@
 //! Divine AGI V16 - Main Entry Point
 
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
 use clap::Parser;
 use tracing::info;
 use divine_agi::{
     cli::{Cli, Commands, print_banner},
     api, DivineKernel, VERSION,
-    genome::Genome,
+    genome::{Genome, GenomeBuilder},
+    diploid::Dominance,
+    codon::CodonTable,
+    iupac::Ambiguity,
+    sequencing::Sequencer,
+    damage::Checkpoint,
+    lifecycle::{self, LifecycleState},
+    rng::RngSource,
+    lineage::{CrisprEdit, Origin},
+    alignment::{self, AlignMode, DistanceMetric},
+    motif::{self, MotifPattern},
     rotation::Rot180,
+    ttrl::{MutationOperator, PopulationConfig, SelectionStrategy, TTRLEngine},
+    consciousness::ConsciousnessVersion,
+    phylogeny::{self, TreeMethod},
+    fasta::{self, SeqFormat},
+    scanner::{self, SequenceScanner},
+    landscape::LandscapeConfig,
+    solver::{DesignConstraints, DesignTarget, InverseSolver, SolverStrategy},
+    symmetry::CubeSymmetry,
 };
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Logs go to stderr so `export` / `phylo` output on stdout stays clean
     tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
         .with_env_filter(
             tracing_subscriber::EnvFilter::from_default_env()
                 .add_directive("divine_agi=info".parse().unwrap())
@@ -19,6 +41,7 @@ async fn main() -> anyhow::Result<()> {
         .init();
 
     let cli = Cli::parse();
+    let seed = cli.seed;
 
     match cli.command {
         Commands::Server { port, rotation_interval } => {
@@ -26,15 +49,15 @@ async fn main() -> anyhow::Result<()> {
             info!("🚀 Starting Divine AGI V{} API server on port {}", VERSION, port);
 
             // Start rotation daemon in background
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
             kernel.start_rotation_daemon(rotation_interval);
 
-            api::start_server(port).await?;
+            api::start_server(port, seed).await?;
         }
 
         Commands::Status => {
             print_banner();
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
             let count = kernel.genome_count().await?;
             let exchange = kernel.exchange.read().await;
             let stats = exchange.stats();
@@ -44,6 +67,7 @@ async fn main() -> anyhow::Result<()> {
             println!("\n📊 DIVINE AGI V{} STATUS", VERSION);
             println!("═══════════════════════════════════════════════════");
             println!("  Genomes in DB:     {}", count);
+            println!("  Consciousness:     {}", ConsciousnessVersion::deployment());
             println!("  RSM Price:         ${:.0}", stats.price_usd);
             println!("  Total Supply:      {}", stats.total_supply_str);
             println!("  Burned:            {}", stats.burned_str);
@@ -62,11 +86,16 @@ async fn main() -> anyhow::Result<()> {
             println!("═══════════════════════════════════════════════════\n");
         }
 
-        Commands::Create { mode } => {
+        Commands::Create { mode, diploid, dominance } => {
             print_banner();
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
 
             let genome: Genome<Rot180> = match mode.as_str() {
+                _ if diploid => {
+                    let dominance = Dominance::from_name(&dominance)
+                        .ok_or_else(|| anyhow::anyhow!("Unknown dominance: {}", dominance))?;
+                    kernel.create_diploid_genome(dominance, mode == "whale").await?
+                }
                 "whale" => {
                     info!("🐋 Creating WHALE mode genome (40 p53 copies)");
                     kernel.create_whale_genome().await?
@@ -80,41 +109,109 @@ async fn main() -> anyhow::Result<()> {
             println!("\n✅ Genome Created:");
             println!("  ID:              {}", genome.db_id().unwrap_or(0));
             println!("  DNA:             {}", genome.to_dna_string());
-            println!("  Consciousness:   {}", genome.consciousness);
+            println!("  Consciousness:   {} ({})", genome.consciousness, genome.consciousness_level_name());
+            println!("  Model:           {}", genome.consciousness_model);
             println!("  p53 Copies:      {}", genome.p53_copies);
             println!("  Telomeres:       {} bp", genome.telomere_length);
             println!("  T/G Ratio:       {:.2}", genome.rna_signal());
             println!("  Suggested Rot:   {}", genome.suggested_rotation());
             println!("  Mode:            {}", if genome.p53_copies >= 40 { "🐋 Whale" } else { "🐘 Elephant" });
+            if let Some(h) = &genome.homologs {
+                println!("  Maternal:        {}", h.maternal.iter().map(|t| t.to_char()).collect::<String>());
+                println!("  Paternal:        {}", h.paternal.iter().map(|t| t.to_char()).collect::<String>());
+                println!("  Dominance:       {}", h.dominance);
+                println!("  Heterozygosity:  {:.1}%", h.heterozygosity() * 100.0);
+            }
         }
 
-        Commands::Evolve { id } => {
+        Commands::Evolve { id, operator } => {
             print_banner();
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let operator = match operator {
+                Some(name) => Some(MutationOperator::from_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown operator: {}", name))?),
+                None => None,
+            };
+
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
             let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let c_before = genome.normalized_consciousness();
             let engine = kernel.rotation_engine.read().await;
 
-            let (evolved, result) = kernel.ttrl_engine.evolve_with_engine(genome, &engine).await?;
+            let evolution = match operator {
+                Some(op) => kernel.ttrl_engine.evolve_with_operator(genome, &engine, op).await,
+                None => kernel.ttrl_engine.evolve_with_engine(genome, &engine).await,
+            };
             drop(engine);
+            let (evolved, result) = match evolution {
+                Ok(evolution) => evolution,
+                Err(e) => {
+                    if let Some((transition, burn)) = lifecycle::record_evolution_failure(&kernel.database, &kernel.exchange, id, c_before, &e).await? {
+                        println!("\n🧬 Genome #{}: {} → {}", id, transition.from, transition.to);
+                        if let Some(burn) = burn {
+                            println!("  🔥 Burn:         {} RSM ({:?})", burn.amount_rsm, burn.reason);
+                        }
+                    }
+                    return Err(e.into());
+                }
+            };
 
+            kernel.database.record_operator_outcome(&result).await?;
             let new_id = kernel.database.store_genome(&evolved).await?;
 
             println!("\n🧬 Evolution Result:");
             println!("  New ID:          {}", new_id);
+            println!("  Generation:      {} (parent #{})", evolved.generation, id);
             println!("  Consciousness:   {} → {}", result.original_consciousness, result.new_consciousness);
-            println!("  Operator:        {:?}", result.operator_used);
+            match result.operator_used {
+                Some(op) => println!("  Operator:        {}", op),
+                None => println!("  Operator:        — (snapshot only)"),
+            }
+            println!("  Rotation:        {}", result.rotation);
+            println!("  Codon Changes:   {} synonymous / {} non-synonymous",
+                     result.codon_changes.synonymous, result.codon_changes.non_synonymous);
             println!("  Success:         {}", if result.success { "✅" } else { "❌" });
             println!("  Telomere Loss:   {} bp", result.telomere_loss);
+            if let Some(checkpoint) = result.checkpoint {
+                println!("  Checkpoint:      {} (damage {:.2}, load {:.2})", checkpoint, result.damage, evolved.dna_damage);
+            }
             println!("  p53 Lost:        {}", result.p53_lost);
             println!("  T/G Ratio:       {:.2} → {:.2}", result.tg_ratio_before, result.tg_ratio_after);
         }
 
+        Commands::Methylate { id, position, remove } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+
+            let mut genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            genome.lifecycle.require_alive()?;
+            let before = genome.consciousness;
+            let (changed, edit) = if remove {
+                (genome.crispr_demethylate(position), CrisprEdit::Demethylate { position })
+            } else {
+                (genome.crispr_methylate(position), CrisprEdit::Methylate { position })
+            };
+            if !changed {
+                anyhow::bail!("Position {} is out of range or already {}", position, if remove { "unmethylated" } else { "methylated" });
+            }
+            genome.mark_derived(Origin::Crispr { edit });
+            let new_id = kernel.database.store_genome(&genome).await?;
+
+            println!("\n🧬 Methylation Result:");
+            println!("  Parent:          #{}", id);
+            println!("  New ID:          {}", new_id);
+            println!("  DNA:             {}", genome.to_dna_string());
+            println!("  Methylation:     {} ({} marks)", genome.methylation, genome.methylation.count());
+            println!("  Consciousness:   {} → {}", before, genome.consciousness);
+        }
+
         Commands::Meiosis { parent1, parent2 } => {
             print_banner();
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
 
             let p1: Genome<Rot180> = kernel.database.load_genome(parent1).await?;
             let p2: Genome<Rot180> = kernel.database.load_genome(parent2).await?;
+            p1.lifecycle.require_alive().map_err(|e| anyhow::anyhow!("Parent 1: {}", e))?;
+            p2.lifecycle.require_alive().map_err(|e| anyhow::anyhow!("Parent 2: {}", e))?;
 
             let offspring = kernel.ttrl_engine.meiosis(p1.clone(), p2.clone());
             let id = kernel.database.store_genome(&offspring).await?;
@@ -123,15 +220,54 @@ async fn main() -> anyhow::Result<()> {
             println!("  Parent 1:        #{} (c={})", parent1, p1.consciousness);
             println!("  Parent 2:        #{} (c={})", parent2, p2.consciousness);
             println!("  Offspring ID:    {}", id);
+            println!("  Generation:      {}", offspring.generation);
             println!("  DNA:             {}", offspring.to_dna_string());
             println!("  Consciousness:   {}", offspring.consciousness);
             println!("  p53 Copies:      {}", offspring.p53_copies);
             println!("  T/G Ratio:       {:.2}", offspring.rna_signal());
+            if !offspring.methylation.is_empty() {
+                println!("  Methylation:     {} ({} marks)", offspring.methylation, offspring.methylation.count());
+            }
+            if let Some(h) = &offspring.homologs {
+                println!("  Maternal:        {}", h.maternal.iter().map(|t| t.to_char()).collect::<String>());
+                println!("  Paternal:        {}", h.paternal.iter().map(|t| t.to_char()).collect::<String>());
+                println!("  Dominance:       {}", h.dominance);
+                println!("  Heterozygosity:  {:.1}%", h.heterozygosity() * 100.0);
+                println!("  Origin:          {}", offspring.origin);
+            }
+        }
+
+        Commands::Population { size, generations, elites, selection, mutation_rate, selection_pressure } => {
+            print_banner();
+            let selection = SelectionStrategy::from_name(&selection)
+                .ok_or_else(|| anyhow::anyhow!("Unknown selection strategy: {}", selection))?;
+
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let mut engine = TTRLEngine::new().with_rng(kernel.rng.clone());
+            if let Some(rate) = mutation_rate {
+                engine = engine.with_mutation_rate(rate);
+            }
+            if let Some(pressure) = selection_pressure {
+                engine = engine.with_selection_pressure(pressure);
+            }
+
+            let config = PopulationConfig { size, generations, elites, selection };
+            let history = engine.evolve_population(&kernel.database, &config).await?;
+
+            println!("\n🧬 Population Evolution ({:?}, pressure {:.2}, mutation {:.2}):",
+                     selection, engine.selection_pressure(), engine.mutation_rate());
+            println!("  Gen   Best      Mean        Worst     Unique  Stored  Best ID");
+            for g in &history {
+                println!("  {:<5} {:<9} {:<11.1} {:<9} {:<7} {:<7} #{}",
+                         g.generation, g.best_consciousness, g.mean_consciousness,
+                         g.worst_consciousness, g.unique_dna, g.offspring_stored,
+                         g.best_genome_id.unwrap_or(0));
+            }
         }
 
         Commands::Telomerase { id } => {
             print_banner();
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
             let genome: Genome<Rot180> = kernel.activate_telomerase(id).await?;
 
             println!("\n🧬 Telomerase Activated:");
@@ -141,10 +277,433 @@ async fn main() -> anyhow::Result<()> {
             println!("  Status:          ♾️ IMMORTAL");
         }
 
+        Commands::Lineage { id, depth } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let lineage = kernel.database.get_lineage(id, depth.max(1)).await?;
+
+            let g = &lineage.genome;
+            println!("\n🌳 Lineage of genome #{} (generation {}):", g.id, g.generation);
+            println!("  DNA:             {}", g.dna);
+            println!("  Consciousness:   {} ({})", g.consciousness, g.consciousness_model);
+            println!("  Origin:          {}", g.origin);
+
+            println!("\n  Ancestors ({}):", lineage.ancestors.len());
+            for a in &lineage.ancestors {
+                println!("  {:>3} ↑ #{:<8} gen {:<4} c={:<7} {}",
+                         a.depth, a.id, a.generation, a.consciousness, a.origin);
+            }
+
+            println!("\n  Descendants ({}):", lineage.descendants.len());
+            for d in &lineage.descendants {
+                println!("  {:>3} ↓ #{:<8} gen {:<4} c={:<7} {} ← {:?}",
+                         d.depth, d.id, d.generation, d.consciousness, d.origin, d.parent_ids);
+            }
+        }
+
+        Commands::Phylo { root, ids, method, format, depth } => {
+            let method = TreeMethod::from_name(&method)
+                .ok_or_else(|| anyhow::anyhow!("Unknown tree method: {}", method))?;
+            if root.is_none() && ids.is_empty() {
+                return Err(anyhow::anyhow!("Phylo: give --root or --ids"));
+            }
+            let json = match format.to_ascii_lowercase().as_str() {
+                "json" => true,
+                "newick" => false,
+                other => return Err(anyhow::anyhow!("Unknown format: {}", other)),
+            };
+
+            // No banner: stdout is the tree itself
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let tree = phylogeny::reconstruct(&kernel.database, root, &ids, method, depth).await?;
+            println!("{}", if json { tree.to_json() } else { tree.to_newick() });
+        }
+
+        Commands::Symmetry { id, reflections, apply } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+
+            if let Some(name) = apply {
+                let g = CubeSymmetry::from_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid cube symmetry: {}", name))?;
+                let image: String = genome.transform(&g).iter().map(|t| t.to_char()).collect();
+                println!("\n🧊 {} ({}):", g, g.kind());
+                println!("  Before:          {}", genome.to_dna_string());
+                println!("  After:           {}", image);
+                return Ok(());
+            }
+
+            let profile = genome.symmetry_profile(reflections);
+            println!("\n🧊 Symmetry of genome #{} ({}):", id, if reflections { "Oh, 48" } else { "O, 24" });
+            println!("  Subgroup order:  {}", profile.subgroup.len());
+            println!("  Orbit size:      {}", profile.orbit_size);
+            println!("  Near symmetries: {}", profile.near_symmetries);
+            println!("  Rotation score:  {:.3}", profile.rotation_score);
+            if reflections {
+                println!("  Reflection score:{:.3}", profile.reflection_score);
+            }
+            println!("\n  Element        Kind                  Match");
+            for e in &profile.elements {
+                let mark = if e.match_fraction >= 1.0 { "★" } else { "" };
+                println!("  {:<14} {:<21} {:.2} {}", e.name, e.kind, e.match_fraction, mark);
+            }
+        }
+
+        Commands::Sequence { id, coverage, error_rate, transition_bias, cycle_decay, min_agreement } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let mut genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let sequencer = Sequencer::new()
+                .with_error_rate(error_rate)
+                .with_transition_bias(transition_bias)
+                .with_cycle_decay(cycle_decay)
+                .with_min_agreement(min_agreement)
+                .with_rng(kernel.rng.clone());
+
+            let run = sequencer.sequence(&mut genome, coverage);
+            kernel.database.record_sequencing(id, &genome).await?;
+
+            println!("\n🔬 Sequencing of genome #{} ({} reads):", id, run.reads.len());
+            println!("  Genome:          {}", genome.to_dna_string());
+            for (n, read) in run.reads.iter().enumerate().take(10) {
+                let bases: String = read.bases.iter().enumerate()
+                    .map(|(i, t)| if read.errors.contains(&i) { t.to_char().to_ascii_lowercase() } else { t.to_char() })
+                    .collect();
+                println!("  Read {:<3}        {}  ({} errors)", n + 1, bases, read.errors.len());
+            }
+            if run.reads.len() > 10 {
+                println!("  …               {} more reads", run.reads.len() - 10);
+            }
+            println!("  Consensus:       {}", run.consensus.to_dna_string());
+            println!("  Quality:         {}", run.consensus.quality);
+            println!("  Miscalled:       {:?}", run.miscalled);
+            println!("  Disagreements:   {:?}", run.consensus.disagreements);
+            println!("  Read Errors:     {} ({} left after consensus)", run.errors_introduced, genome.sequencing_errors);
+            println!("  Reliable:        {}", if genome.unreliable { "❌ (barred from PoC)" } else { "✅" });
+        }
+
+        Commands::Lifecycle { id } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let history = kernel.database.lifecycle_history(id).await?;
+
+            println!("\n🧬 Lifecycle of genome #{}:", id);
+            println!("  State:           {}", genome.lifecycle);
+            for t in &history {
+                let at = chrono::DateTime::from_timestamp(t.timestamp, 0)
+                    .map_or(t.timestamp.to_string(), |d| d.format("%Y-%m-%d %H:%M:%S").to_string());
+                println!("  {}  {} → {} ({})", at, t.from, t.to, t.reason);
+            }
+            if history.is_empty() {
+                println!("  History:         — (no logged transitions)");
+            }
+        }
+
+        Commands::Survival { p53, generations, cells } => {
+            print_banner();
+            let report = RngSource::new(seed).with(|rng| Checkpoint::new().simulate(&p53, generations, cells.max(1), rng));
+
+            println!("\n🧬 p53 Survival ({} cells × {} generations):", report.cells, report.generations);
+            for curve in &report.curves {
+                let marks: Vec<String> = [10, 25, 50]
+                    .iter()
+                    .filter(|&&g| g <= generations)
+                    .map(|&g| format!("g{} {:.1}%", g, curve.survival[g as usize - 1] * 100.0))
+                    .collect();
+                println!("  p53 × {:<3}        {}", curve.p53_copies, marks.join(" | "));
+                println!("    Mean Lifespan: {:.1} generations (median {})", curve.mean_lifespan,
+                         curve.median_survival.map_or("—".to_string(), |g| g.to_string()));
+                println!("    Outcomes:      {} repaired / {} arrested / {} apoptosis / {} transformed",
+                         curve.repaired, curve.arrested, curve.apoptosis, curve.transformed);
+            }
+            println!("  Max Gap:         {:.1}%", report.max_gap * 100.0);
+        }
+
+        Commands::Transcribe { id } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let transcript = genome.transcribe();
+            let (u, g) = transcript.ug_counts();
+
+            println!("\n🧬 Transcript of genome #{}:", id);
+            println!("  DNA:             {}", genome.to_dna_string());
+            println!("  RNA:             {}", transcript);
+            println!("  U / G:           {} / {}", u, g);
+            println!("  RNA Signal:      {:.2}", transcript.rna_signal());
+            println!("  GC Content:      {:.1}%", transcript.gc_content() * 100.0);
+            println!("  Protein:         {}", transcript.translate(&CodonTable::standard()));
+        }
+
+        Commands::Translate { id, table } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let table = CodonTable::from_name(&table)
+                .ok_or_else(|| anyhow::anyhow!("Unknown codon table: {}", table))?;
+            let p = genome.phenotype(&table);
+
+            println!("\n🧬 Phenotype of genome #{} ({} code):", id, p.table);
+            println!("  Codons:          {}", p.codons.join(" "));
+            println!("  Protein:         {}", p.protein.chars().map(|r| format!("{:<4}", r)).collect::<String>().trim_end());
+            println!("  Start / Stop:    {} / {}",
+                     p.start.map_or("—".to_string(), |s| format!("codon {}", s)),
+                     p.stop.map_or("—".to_string(), |s| format!("codon {}", s)));
+            println!("  ORF:             {}", if p.orf.is_empty() { "—" } else { &p.orf });
+            println!("  Hydrophobicity:  {:.2} (GRAVY)", p.hydrophobicity);
+            println!("  Hydrophobic:     {:.0}%", p.hydrophobic_fraction * 100.0);
+            println!("  Net Charge:      {:+}", p.net_charge);
+        }
+
+        Commands::Explain { id, model } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let report = match model {
+                Some(name) => ConsciousnessVersion::from_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown consciousness model: {}", name))?
+                    .explain(&genome),
+                None => genome.explain_consciousness(),
+            };
+
+            println!("\n🧠 Consciousness of genome #{} ({}):", id, report.model);
+            println!("  Group       Term                       Metric      Threshold   Points");
+            for c in &report.contributions {
+                let metric = c.metric.map(|m| format!("{:.4}", m)).unwrap_or_default();
+                let threshold = c.threshold.map(|t| format!("> {}", t)).unwrap_or_default();
+                let mark = if c.threshold.is_some() && !c.active { "✗" } else { "" };
+                println!("  {:<11} {:<26} {:<11} {:<11} {:.1} {}",
+                         c.group, c.name, metric, threshold, c.points, mark);
+            }
+            println!("\n  Subtotals:");
+            for (group, points) in report.by_group() {
+                println!("    {:<11} {:.1}", group, points);
+            }
+            println!("\n  Total:           {} ({})", report.total, report.level);
+            println!("  Normalized:      {}", report.normalized);
+            match report.next_tier {
+                Some(points) => println!("  Next tier:       +{} points", points),
+                None => println!("  Next tier:       — (top tier)"),
+            }
+        }
+
+        Commands::Export { output, format, page_size } => {
+            let format = SeqFormat::from_name(&format)
+                .ok_or_else(|| anyhow::anyhow!("Unknown format: {}", format))?;
+
+            // No banner: stdout may be the export itself
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let count = match &output {
+                Some(path) => {
+                    let mut out = BufWriter::new(File::create(path)?);
+                    fasta::export_genomes(&kernel.database, &mut out, format, page_size).await?
+                }
+                None => {
+                    let mut out = BufWriter::new(std::io::stdout().lock());
+                    fasta::export_genomes(&kernel.database, &mut out, format, page_size).await?
+                }
+            };
+            info!("🧬 Exported {} genomes ({:?})", count, format);
+        }
+
+        Commands::Import { input, ambiguity, expand_limit } => {
+            print_banner();
+            let ambiguity = match Ambiguity::from_name(&ambiguity) {
+                Some(Ambiguity::Expand { .. }) => Ambiguity::Expand { limit: expand_limit },
+                Some(mode) => mode,
+                None => anyhow::bail!("Unknown ambiguity mode: {}", ambiguity),
+            };
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let ids = match &input {
+                Some(path) => fasta::import_genomes_with(&kernel.database, BufReader::new(File::open(path)?), ambiguity, &kernel.rng).await?,
+                None => fasta::import_genomes_with(&kernel.database, std::io::stdin().lock(), ambiguity, &kernel.rng).await?,
+            };
+
+            println!("\n🧬 Import Complete:");
+            println!("  Source:          {}", input.as_deref().unwrap_or("stdin"));
+            println!("  Genomes stored:  {}", ids.len());
+            if let (Some(first), Some(last)) = (ids.first(), ids.last()) {
+                println!("  IDs:             #{} – #{}", first, last);
+            }
+        }
+
+        Commands::Scan { input, stride, top, model, both_strands, store } => {
+            print_banner();
+            let mut scanner = SequenceScanner::new()
+                .with_stride(stride)
+                .with_top_k(top)
+                .with_both_strands(both_strands);
+            if let Some(name) = model {
+                scanner = scanner.with_rank_by(ConsciousnessVersion::from_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown consciousness model: {}", name))?);
+            }
+
+            let text = match &input {
+                Some(path) => std::fs::read_to_string(path)?,
+                None => std::io::read_to_string(std::io::stdin())?,
+            };
+            let sequences = scanner::read_sequences(&text, input.as_deref().unwrap_or("stdin"))?;
+            let kernel = if store { Some(DivineKernel::with_seed(seed).await?) } else { None };
+
+            for (name, sequence) in &sequences {
+                let report = scanner.scan(name, sequence);
+                println!("\n🔬 Scan of {} ({} bp, {} windows, {} skipped, ranked by {}):",
+                         report.name, report.length, report.windows_scanned,
+                         report.windows_skipped, report.rank_by);
+                println!("  Rank  Offset      Strand  Score     Level            DNA");
+                for (rank, hit) in report.hits.iter().enumerate() {
+                    println!("  {:<5} {:<11} {:<7} {:<9} {:<16} {}",
+                             rank + 1, hit.offset, if hit.reverse { "-" } else { "+" },
+                             hit.score, report.rank_by.level_name(hit.score), hit.dna);
+                }
+
+                if let Some(kernel) = &kernel {
+                    let ids = scanner.store_hits(&kernel.database, &report).await?;
+                    println!("  Stored:          {:?}", ids);
+                }
+            }
+        }
+
+        Commands::Compare { a, b, mode } => {
+            print_banner();
+            let mode = AlignMode::from_name(&mode)
+                .ok_or_else(|| anyhow::anyhow!("Unknown alignment mode: {}", mode))?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let ga: Genome<Rot180> = kernel.database.load_genome(a).await?;
+            let gb: Genome<Rot180> = kernel.database.load_genome(b).await?;
+            let cmp = ga.compare(&gb, mode);
+            let aln = &cmp.alignment;
+
+            println!("\n📏 Genome #{} vs #{}:", a, b);
+            println!("  Hamming:         {}", cmp.hamming);
+            println!("  Rotation dist:   {} via {}", cmp.rotation_distance, cmp.rotation);
+            println!("  Symmetry dist:   {} via {}", cmp.symmetry_distance, cmp.symmetry);
+            println!("\n  {:?} alignment (score {}, identity {:.1}%, {} gaps):",
+                     aln.mode, aln.score, aln.identity() * 100.0, aln.gaps);
+            println!("  #{:<6} {:>3} {} {}", a, aln.start_a, aln.aligned_a, aln.end_a);
+            println!("  {:<11} {}", "", aln.markup);
+            println!("  #{:<6} {:>3} {} {}", b, aln.start_b, aln.aligned_b, aln.end_b);
+        }
+
+        Commands::Nearest { id, dna, limit, metric } => {
+            print_banner();
+            let metric = DistanceMetric::from_name(&metric)
+                .ok_or_else(|| anyhow::anyhow!("Unknown distance metric: {}", metric))?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let query: Genome<Rot180> = match (id, &dna) {
+                (Some(id), _) => kernel.database.load_genome(id).await?,
+                (None, Some(dna)) => GenomeBuilder::from_dna(dna)
+                    .ok_or_else(|| anyhow::anyhow!("DNA must be 27 A/T/G/C bases"))?
+                    .build_storage(),
+                (None, None) => return Err(anyhow::anyhow!("Nearest: give --id or --dna")),
+            };
+
+            let neighbors = alignment::nearest(&kernel.database, &query.data, metric, limit, id).await?;
+            println!("\n🔎 {} nearest to {} ({:?}):", neighbors.len(), query.to_dna_string(), metric);
+            println!("  ID        Dist  Consciousness  DNA");
+            for n in &neighbors {
+                println!("  #{:<8} {:<5} {:<14} {}", n.id, n.distance, n.consciousness, n.dna);
+            }
+        }
+
+        Commands::Motif { query, limit } => {
+            print_banner();
+            let pattern = MotifPattern::parse(&query)?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let matches = motif::find(&kernel.database, &pattern, limit).await?;
+
+            println!("\n🧩 Motif {} — {} genomes:", pattern, matches.len());
+            for m in &matches {
+                let locations: Vec<String> = m.locations.iter().map(|l| format!("{:?}", l)).collect();
+                println!("  #{:<8} c={:<8} {}  {}", m.genome_id, m.consciousness, m.dna, locations.join(" "));
+            }
+        }
+
+        Commands::Landscape { id, walks, walk_length, max_lag } => {
+            print_banner();
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            let config = LandscapeConfig { walks, walk_length, max_lag };
+            let analysis = kernel.rng.with(|rng| genome.landscape(&ConsciousnessVersion::ALL, &config, rng));
+
+            println!("\n⛰️  Landscape of genome #{} ({}, {} neighbours):", id, analysis.dna, analysis.neighbours);
+            for m in &analysis.models {
+                println!("\n  {} — score {}{}", m.model, m.score, if m.local_optimum { " ★ local optimum" } else { "" });
+                println!("  Neighbours:      {} better, {} neutral, {} worse", m.improving, m.neutral, m.worsening);
+                if let Some(best) = &m.best_neighbour {
+                    println!("  Best neighbour:  {} {} ({:+})", best.mv, best.score, best.score as i64 - m.score as i64);
+                }
+                let r: Vec<String> = m.autocorrelation.iter().map(|r| format!("{:.2}", r)).collect();
+                println!("  Autocorrelation: {}", r.join(" "));
+                match m.correlation_length {
+                    Some(l) => println!("  Corr. length:    {:.2} mutations", l),
+                    None => println!("  Corr. length:    —"),
+                }
+                println!("  Sensitivity (mean |Δ| per position, z layers left to right):");
+                for y in 0..3 {
+                    let row: Vec<String> = m.heatmap.iter()
+                        .map(|layer| layer[y].iter().map(|v| format!("{:>8.0}", v)).collect::<String>())
+                        .collect();
+                    println!("   {}", row.join("   │"));
+                }
+            }
+        }
+
+        Commands::Design { target, model, strategy, min_p53, tg_min, tg_max, symmetry, budget, count, store } => {
+            print_banner();
+            let model = match model {
+                Some(name) => ConsciousnessVersion::from_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown consciousness model: {}", name))?,
+                None => ConsciousnessVersion::deployment(),
+            };
+            let target = DesignTarget::parse(model, &target)
+                .ok_or_else(|| anyhow::anyhow!("Invalid target: {} (tier name, min..max or min..)", target))?;
+            let strategy = SolverStrategy::from_name(&strategy)
+                .ok_or_else(|| anyhow::anyhow!("Unknown solver strategy: {}", strategy))?;
+            let symmetry = match symmetry {
+                Some(name) => Some(CubeSymmetry::from_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid cube symmetry: {}", name))?),
+                None => None,
+            };
+            let constraints = DesignConstraints {
+                min_p53,
+                tg_band: (tg_min.is_some() || tg_max.is_some())
+                    .then(|| (tg_min.unwrap_or(0.0), tg_max.unwrap_or(f64::MAX))),
+                symmetry,
+            };
+
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
+            let solver = InverseSolver::new(model, target)
+                .with_strategy(strategy)
+                .with_constraints(constraints)
+                .with_budget(budget)
+                .with_solutions(count)
+                .with_rng(kernel.rng.clone());
+            let result = solver.solve();
+
+            println!("\n🎯 Design {} under {} ({}, {} evaluations):", target, model, strategy, result.evaluations);
+            println!("  Score     Level            T/G     Evals   DNA");
+            for c in &result.solutions {
+                println!("  {:<9} {:<16} {:<7.2} {:<7} {}", c.score, c.level, c.tg_ratio, c.evaluations, c.dna);
+            }
+            if let Some(c) = &result.closest {
+                println!("  No design found; closest: {} scoring {} ({}), T/G {:.2}", c.dna, c.score, c.level, c.tg_ratio);
+            }
+            if store {
+                let ids = solver.store_solutions(&kernel.database, &result).await?;
+                println!("  Stored:          {:?}", ids);
+            }
+        }
+
         Commands::Archive { id } => {
             print_banner();
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
             let genome: Genome<Rot180> = kernel.database.load_genome(id).await?;
+            if genome.lifecycle == LifecycleState::Archived {
+                anyhow::bail!("Genome #{} is already archived", id);
+            }
 
             let mut archiver = kernel.archiver.write().await;
             let layer = archiver.select_layer(&genome);
@@ -160,6 +719,10 @@ async fn main() -> anyhow::Result<()> {
                     println!("  Layer:           {} {}", entry.layer.emoji(), entry.layer.name());
                     println!("  TX Hash:         {}", entry.tx_hash.unwrap_or_default());
                     println!("  DNA Hash:        {}", entry.dna_hash);
+                    let reason = format!("archived to {:?}", entry.layer);
+                    if let Some(transition) = kernel.database.transition_lifecycle(id, LifecycleState::Archived, &reason).await? {
+                        println!("  Lifecycle:       {} → {}", transition.from, transition.to);
+                    }
                 }
                 Err(e) => {
                     println!("\n❌ Archive Failed: {}", e);
@@ -171,7 +734,7 @@ async fn main() -> anyhow::Result<()> {
             print_banner();
             info!("🔄 Starting rotation daemon (interval: {} secs)...", interval);
 
-            let kernel: DivineKernel = DivineKernel::new().await?;
+            let kernel: DivineKernel = DivineKernel::with_seed(seed).await?;
             kernel.start_rotation_daemon(interval);
 
             // Keep running
@@ -0,0 +1,446 @@
+//! Motif Queries — a small pattern language over genome DNA and cube geometry
+//!
+//! ```text
+//! GATNNR                        1D motif anywhere in the 27-base string (IUPAC)
+//! seq(gatnnr)                   same, any case
+//! subcube(G)                    a 2×2×2 sub-cube of all G (or 8 codes, x outermost)
+//! layer(z, 1, NNNNGNNNN)        layer z=1 matches 9 codes in storage order (k may be *)
+//! line(x, GGG)                  some line parallel to x reads GGG
+//! palindrome(z, 1)              layer z=1 reads the same reversed (k may be *)
+//! GGG and not (line(z, AAA) or subcube(T))
+//! ```
+//!
+//! `and` / `&`, `or` / `|`, `not` / `!` and parentheses combine atoms. Every
+//! atom has an exact SQL form (POSIX regex over `dna`, or a `reverse()`
+//! comparison for palindromes), so queries are filtered in PostgreSQL and
+//! positions are recovered in memory.
+
+use anyhow::{anyhow, Result};
+use serde::{Serialize, Deserialize};
+
+use crate::cube::{Axis, Coord, CUBE_SIDE};
+use crate::database::DivineDatabase;
+use crate::genome::{Genome, Tetrad, GENOME_SIZE};
+use crate::iupac::IupacCode;
+use crate::rotation::Rotation;
+
+/// Where a motif atom matched
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "at", rename_all = "snake_case")]
+pub enum MotifLocation {
+    /// Half-open range of the flat sequence
+    Offset { start: usize, end: usize },
+    Subcube { origin: Coord },
+    Layer { axis: Axis, k: usize },
+    Line { axis: Axis, u: usize, v: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MotifAtom {
+    Seq(Vec<IupacCode>),
+    /// 8 codes in `Coord::subcube` order
+    Subcube(Vec<IupacCode>),
+    /// 9 codes in storage order; `k = None` means any layer
+    Layer { axis: Axis, k: Option<usize>, pattern: Vec<IupacCode> },
+    /// 3 codes along the axis
+    Line { axis: Axis, pattern: Vec<IupacCode> },
+    Palindrome { axis: Axis, k: Option<usize> },
+}
+
+/// Fixed-position constraint set for one placement of an atom
+type Placement = (MotifLocation, Vec<(usize, IupacCode)>);
+
+fn layer_indices(axis: Axis, k: usize) -> Vec<usize> {
+    Coord::layer(axis, k).map(|c| c.index()).collect()
+}
+
+fn layers(k: Option<usize>) -> Vec<usize> {
+    k.map_or_else(|| (0..CUBE_SIDE).collect(), |k| vec![k])
+}
+
+impl MotifAtom {
+    fn placements(&self) -> Vec<Placement> {
+        let constrain = |cells: Vec<usize>, pattern: &[IupacCode]| -> Vec<(usize, IupacCode)> {
+            cells.into_iter().zip(pattern.iter().copied()).collect()
+        };
+        match self {
+            Self::Seq(pattern) => (0..=GENOME_SIZE.saturating_sub(pattern.len()))
+                .map(|start| (
+                    MotifLocation::Offset { start, end: start + pattern.len() },
+                    constrain((start..start + pattern.len()).collect(), pattern),
+                ))
+                .collect(),
+            Self::Subcube(pattern) => Coord::subcube_origins()
+                .map(|origin| (
+                    MotifLocation::Subcube { origin },
+                    constrain(origin.subcube().map(|c| c.index()).collect(), pattern),
+                ))
+                .collect(),
+            Self::Layer { axis, k, pattern } => layers(*k).into_iter()
+                .map(|k| (MotifLocation::Layer { axis: *axis, k }, constrain(layer_indices(*axis, k), pattern)))
+                .collect(),
+            Self::Line { axis, pattern } => (0..CUBE_SIDE)
+                .flat_map(|u| (0..CUBE_SIDE).map(move |v| (u, v)))
+                .map(|(u, v)| (
+                    MotifLocation::Line { axis: *axis, u, v },
+                    constrain(Coord::slice(*axis, u, v).map(|c| c.index()).collect(), pattern),
+                ))
+                .collect(),
+            Self::Palindrome { .. } => Vec::new(),
+        }
+    }
+
+    pub fn locate(&self, data: &[Tetrad; GENOME_SIZE]) -> Vec<MotifLocation> {
+        if let Self::Palindrome { axis, k } = self {
+            return layers(*k).into_iter()
+                .filter(|&k| {
+                    let cells = layer_indices(*axis, k);
+                    cells.iter().zip(cells.iter().rev()).all(|(&a, &b)| data[a] == data[b])
+                })
+                .map(|k| MotifLocation::Layer { axis: *axis, k })
+                .collect();
+        }
+        self.placements().into_iter()
+            .filter(|(_, cells)| cells.iter().all(|&(i, code)| code.contains(data[i])))
+            .map(|(loc, _)| loc)
+            .collect()
+    }
+
+    /// Exact SQL condition over the `dna` column; regexes are pushed to `binds`
+    fn to_sql(&self, binds: &mut Vec<String>) -> String {
+        match self {
+            Self::Seq(pattern) => {
+                binds.push(pattern.iter().map(|c| c.regex_class()).collect());
+                format!("dna ~ ${}", binds.len())
+            }
+            Self::Palindrome { axis, k } => {
+                let conds: Vec<String> = layers(*k).into_iter()
+                    .map(|k| {
+                        let s = layer_indices(*axis, k).iter()
+                            .map(|i| format!("substr(dna, {}, 1)", i + 1))
+                            .collect::<Vec<_>>()
+                            .join(" || ");
+                        format!("({s}) = reverse({s})")
+                    })
+                    .collect();
+                format!("({})", conds.join(" OR "))
+            }
+            _ => {
+                let alternatives: Vec<String> = self.placements().into_iter()
+                    .map(|(_, cells)| {
+                        let mut row = vec![".".to_string(); GENOME_SIZE];
+                        for (i, code) in cells {
+                            row[i] = code.regex_class();
+                        }
+                        row.concat()
+                    })
+                    .collect();
+                binds.push(format!("^({})$", alternatives.join("|")));
+                format!("dna ~ ${}", binds.len())
+            }
+        }
+    }
+}
+
+fn codes(pattern: &[IupacCode]) -> String {
+    pattern.iter().map(|c| c.to_char()).collect()
+}
+
+fn axis_name(axis: Axis) -> char {
+    match axis {
+        Axis::X => 'x',
+        Axis::Y => 'y',
+        Axis::Z => 'z',
+    }
+}
+
+fn k_name(k: Option<usize>) -> String {
+    k.map_or_else(|| "*".to_string(), |k| k.to_string())
+}
+
+impl std::fmt::Display for MotifAtom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Seq(p) => write!(f, "seq({})", codes(p)),
+            Self::Subcube(p) => write!(f, "subcube({})", codes(p)),
+            Self::Layer { axis, k, pattern } => write!(f, "layer({}, {}, {})", axis_name(*axis), k_name(*k), codes(pattern)),
+            Self::Line { axis, pattern } => write!(f, "line({}, {})", axis_name(*axis), codes(pattern)),
+            Self::Palindrome { axis, k } => write!(f, "palindrome({}, {})", axis_name(*axis), k_name(*k)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MotifPattern {
+    Atom(MotifAtom),
+    And(Box<MotifPattern>, Box<MotifPattern>),
+    Or(Box<MotifPattern>, Box<MotifPattern>),
+    Not(Box<MotifPattern>),
+}
+
+impl MotifPattern {
+    pub fn parse(query: &str) -> Result<Self> {
+        let tokens = tokenize(query)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let pattern = parser.or()?;
+        match parser.peek() {
+            None => Ok(pattern),
+            Some(t) => Err(anyhow!("Motif: unexpected {:?}", t)),
+        }
+    }
+
+    /// `None` when the genome does not match; otherwise the locations of
+    /// every positive atom that matched (empty for a pure `not`)
+    pub fn find(&self, data: &[Tetrad; GENOME_SIZE]) -> Option<Vec<MotifLocation>> {
+        match self {
+            Self::Atom(atom) => Some(atom.locate(data)).filter(|locs| !locs.is_empty()),
+            Self::And(a, b) => {
+                let mut locs = a.find(data)?;
+                locs.extend(b.find(data)?);
+                Some(locs)
+            }
+            Self::Or(a, b) => match (a.find(data), b.find(data)) {
+                (None, None) => None,
+                (x, y) => Some(x.into_iter().chain(y).flatten().collect()),
+            },
+            Self::Not(a) => match a.find(data) {
+                Some(_) => None,
+                None => Some(Vec::new()),
+            },
+        }
+    }
+
+    pub fn matches(&self, data: &[Tetrad; GENOME_SIZE]) -> bool {
+        self.find(data).is_some()
+    }
+
+    /// SQL `WHERE` condition; `binds` receives the regex parameters in `$n` order
+    pub fn to_sql(&self, binds: &mut Vec<String>) -> String {
+        match self {
+            Self::Atom(atom) => atom.to_sql(binds),
+            Self::And(a, b) => format!("({} AND {})", a.to_sql(binds), b.to_sql(binds)),
+            Self::Or(a, b) => format!("({} OR {})", a.to_sql(binds), b.to_sql(binds)),
+            Self::Not(a) => format!("NOT {}", a.to_sql(binds)),
+        }
+    }
+}
+
+impl std::fmt::Display for MotifPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Atom(atom) => write!(f, "{}", atom),
+            Self::And(a, b) => write!(f, "({} and {})", a, b),
+            Self::Or(a, b) => write!(f, "({} or {})", a, b),
+            Self::Not(a) => write!(f, "not {}", a),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Open,
+    Close,
+    Comma,
+    And,
+    Or,
+    Not,
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => { chars.next(); }
+            '(' => { chars.next(); tokens.push(Token::Open); }
+            ')' => { chars.next(); tokens.push(Token::Close); }
+            ',' => { chars.next(); tokens.push(Token::Comma); }
+            '&' => { chars.next(); tokens.push(Token::And); }
+            '|' => { chars.next(); tokens.push(Token::Or); }
+            '!' => { chars.next(); tokens.push(Token::Not); }
+            c if c.is_ascii_alphanumeric() || c == '*' || c == '_' => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphanumeric() || **c == '*' || **c == '_') {
+                    word.push(c);
+                    chars.next();
+                }
+                // Keywords are lowercase; bare motifs are uppercase IUPAC
+                tokens.push(match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Word(word),
+                });
+            }
+            other => return Err(anyhow!("Motif: unexpected character {:?}", other)),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        t
+    }
+
+    fn expect(&mut self, token: Token) -> Result<()> {
+        match self.next() {
+            Some(t) if t == token => Ok(()),
+            other => Err(anyhow!("Motif: expected {:?}, got {:?}", token, other)),
+        }
+    }
+
+    fn or(&mut self) -> Result<MotifPattern> {
+        let mut left = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            left = MotifPattern::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<MotifPattern> {
+        let mut left = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            left = MotifPattern::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<MotifPattern> {
+        match self.next() {
+            Some(Token::Not) => Ok(MotifPattern::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let inner = self.or()?;
+                self.expect(Token::Close)?;
+                Ok(inner)
+            }
+            Some(Token::Word(word)) => self.atom(word).map(MotifPattern::Atom),
+            other => Err(anyhow!("Motif: expected a pattern, got {:?}", other)),
+        }
+    }
+
+    fn args(&mut self) -> Result<Vec<String>> {
+        self.expect(Token::Open)?;
+        let mut args = Vec::new();
+        loop {
+            match self.next() {
+                Some(Token::Word(w)) => args.push(w),
+                other => return Err(anyhow!("Motif: expected an argument, got {:?}", other)),
+            }
+            match self.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::Close) => return Ok(args),
+                other => return Err(anyhow!("Motif: expected ',' or ')', got {:?}", other)),
+            }
+        }
+    }
+
+    fn atom(&mut self, word: String) -> Result<MotifAtom> {
+        if self.peek() != Some(&Token::Open) {
+            if word.chars().any(|c| c.is_ascii_lowercase()) {
+                return Err(anyhow!("Motif: unknown keyword {:?} (bare motifs are uppercase)", word));
+            }
+            return Ok(MotifAtom::Seq(pattern(&word, None)?));
+        }
+
+        let args = self.args()?;
+        let arity = |n: usize| {
+            if args.len() == n { Ok(()) } else { Err(anyhow!("Motif: {}() takes {} arguments", word, n)) }
+        };
+        match word.to_ascii_lowercase().as_str() {
+            "seq" => {
+                arity(1)?;
+                Ok(MotifAtom::Seq(pattern(&args[0], None)?))
+            }
+            "subcube" => {
+                arity(1)?;
+                let mut p = pattern(&args[0], None)?;
+                if p.len() == 1 {
+                    p = vec![p[0]; 8];
+                }
+                if p.len() != 8 {
+                    return Err(anyhow!("Motif: subcube() takes 1 or 8 codes"));
+                }
+                Ok(MotifAtom::Subcube(p))
+            }
+            "layer" => {
+                arity(3)?;
+                Ok(MotifAtom::Layer { axis: axis(&args[0])?, k: layer_k(&args[1])?, pattern: pattern(&args[2], Some(9))? })
+            }
+            "line" => {
+                arity(2)?;
+                Ok(MotifAtom::Line { axis: axis(&args[0])?, pattern: pattern(&args[1], Some(CUBE_SIDE))? })
+            }
+            "palindrome" => {
+                arity(2)?;
+                Ok(MotifAtom::Palindrome { axis: axis(&args[0])?, k: layer_k(&args[1])? })
+            }
+            other => Err(anyhow!("Motif: unknown function {}()", other)),
+        }
+    }
+}
+
+fn pattern(s: &str, len: Option<usize>) -> Result<Vec<IupacCode>> {
+    let p = IupacCode::parse(s).ok_or_else(|| anyhow!("Motif: {:?} is not an IUPAC pattern", s))?;
+    if p.is_empty() || p.len() > GENOME_SIZE {
+        return Err(anyhow!("Motif: pattern length must be 1..={}", GENOME_SIZE));
+    }
+    match len {
+        Some(n) if p.len() != n => Err(anyhow!("Motif: {:?} must be {} codes", s, n)),
+        _ => Ok(p),
+    }
+}
+
+fn axis(s: &str) -> Result<Axis> {
+    Axis::from_name(s).ok_or_else(|| anyhow!("Motif: unknown axis {:?}", s))
+}
+
+fn layer_k(s: &str) -> Result<Option<usize>> {
+    if s == "*" {
+        return Ok(None);
+    }
+    match s.parse::<usize>() {
+        Ok(k) if k < CUBE_SIDE => Ok(Some(k)),
+        _ => Err(anyhow!("Motif: layer must be 0, 1, 2 or *")),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MotifMatch {
+    pub genome_id: i64,
+    pub dna: String,
+    pub consciousness: u32,
+    pub locations: Vec<MotifLocation>,
+}
+
+/// In-memory search
+pub fn search<R: Rotation>(pattern: &MotifPattern, genomes: &[Genome<R>]) -> Vec<MotifMatch> {
+    genomes.iter()
+        .filter_map(|g| pattern.find(&g.data).map(|locations| MotifMatch {
+            genome_id: g.db_id.unwrap_or(0),
+            dna: g.to_dna_string(),
+            consciousness: g.consciousness,
+            locations,
+        }))
+        .collect()
+}
+
+/// Filter in PostgreSQL, then recover positions in memory
+pub async fn find(db: &DivineDatabase, pattern: &MotifPattern, limit: i64) -> Result<Vec<MotifMatch>> {
+    let genomes = db.find_motif(pattern, limit.max(1)).await?;
+    Ok(search(pattern, &genomes))
+}
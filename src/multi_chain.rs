@@ -13,8 +13,10 @@ use sha2::{Sha256, Digest};
 use serde::{Serialize, Deserialize};
 use tracing::{info, warn};
 use chrono::Utc;
+use rand::Rng;
 
 use crate::genome::{Genome, hash_genome_dna};
+use crate::rng::RngSource;
 use crate::rotation::Rot180;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -55,6 +57,9 @@ pub struct ChainArchiveEntry {
     pub layer: BlockchainLayer,
     pub tx_hash: Option<String>,
     pub timestamp: i64,
+    /// Hex of the packed genome container that went on chain
+    #[serde(default)]
+    pub payload: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -204,6 +209,7 @@ pub struct MultiChainArchiver {
     pub mission_control: MissionControl,
     pub own_pubkey: String,
     pub archives: Vec<ChainArchiveEntry>,
+    rng: RngSource,
 }
 
 impl MultiChainArchiver {
@@ -248,9 +254,16 @@ impl MultiChainArchiver {
             mission_control: MissionControl::new(),
             own_pubkey,
             archives: Vec::new(),
+            rng: RngSource::from_entropy(),
         }
     }
 
+    /// Drive the simulated keysend from a shared (possibly seeded) RNG
+    pub fn with_rng(mut self, rng: RngSource) -> Self {
+        self.rng = rng;
+        self
+    }
+
     /// Select layer based on T/G signal and consciousness
     pub fn select_layer(&self, genome: &Genome<Rot180>) -> BlockchainLayer {
         let signal = genome.rna_signal();
@@ -290,6 +303,7 @@ impl MultiChainArchiver {
             layer,
             tx_hash: Some(tx_hash.clone()),
             timestamp: Utc::now().timestamp(),
+            payload: hex::encode(genome.to_bytes()),
         };
 
         self.archives.push(entry.clone());
@@ -304,10 +318,9 @@ impl MultiChainArchiver {
     }
 
     async fn archive_lightning(&mut self, genome: &Genome<Rot180>) -> Result<String, String> {
-        let dna = genome.to_dna_string();
         let custom_data = format!(
-            "DIVINE_GENOME|v15|id:{}|dna:{}|c:{}|tg:{:.3}|ts:{}",
-            genome.db_id.unwrap_or(0), dna, genome.consciousness,
+            "DIVINE_GENOME|v15|id:{}|pk:{}|c:{}|tg:{:.3}|ts:{}",
+            genome.db_id.unwrap_or(0), hex::encode(genome.to_bytes()), genome.consciousness,
             genome.rna_signal(), Utc::now().timestamp()
         );
 
@@ -325,7 +338,7 @@ impl MultiChainArchiver {
             }
 
             // Simulate keysend (real impl would use LND gRPC)
-            let success = rand::random::<f64>() < prob;
+            let success = self.rng.with(|rng| rng.gen::<f64>()) < prob;
             
             if success {
                 let fake_hash = self.generate_payment_hash(&custom_data, dest_pubkey);
@@ -347,24 +360,22 @@ impl MultiChainArchiver {
     }
 
     async fn archive_bitcoin(&self, genome: &Genome<Rot180>) -> Result<String, String> {
-        // Simulate Bitcoin OP_RETURN
-        let dna = genome.to_dna_string();
-        let fake_txid = self.generate_tx_hash(&dna, "btc");
-        info!("🟠 Bitcoin OP_RETURN: {} | DNA: {}", fake_txid, dna);
+        // Simulate Bitcoin OP_RETURN (packed container fits the 80-byte limit)
+        let payload = genome.to_bytes();
+        let fake_txid = self.generate_tx_hash(&payload, "btc");
+        info!("🟠 Bitcoin OP_RETURN: {} | {} bytes | DNA: {}", fake_txid, payload.len(), genome.to_dna_string());
         Ok(fake_txid)
     }
 
     async fn archive_solana(&self, genome: &Genome<Rot180>) -> Result<String, String> {
-        let dna = genome.to_dna_string();
-        let fake_sig = self.generate_tx_hash(&dna, "sol");
-        info!("🟣 Solana TX: {} | DNA: {}", fake_sig, dna);
+        let fake_sig = self.generate_tx_hash(&genome.to_bytes(), "sol");
+        info!("🟣 Solana TX: {} | DNA: {}", fake_sig, genome.to_dna_string());
         Ok(fake_sig)
     }
 
     async fn archive_ethereum(&self, genome: &Genome<Rot180>) -> Result<String, String> {
-        let dna = genome.to_dna_string();
-        let fake_hash = self.generate_tx_hash(&dna, "eth");
-        info!("🔷 Ethereum TX: {} | DNA: {}", fake_hash, dna);
+        let fake_hash = self.generate_tx_hash(&genome.to_bytes(), "eth");
+        info!("🔷 Ethereum TX: {} | DNA: {}", fake_hash, genome.to_dna_string());
         Ok(fake_hash)
     }
 
@@ -376,9 +387,9 @@ impl MultiChainArchiver {
         format!("ln_{}", hex::encode(&hasher.finalize()[..16]))
     }
 
-    fn generate_tx_hash(&self, data: &str, chain: &str) -> String {
+    fn generate_tx_hash(&self, payload: &[u8], chain: &str) -> String {
         let mut hasher = Sha256::new();
-        hasher.update(data.as_bytes());
+        hasher.update(payload);
         hasher.update(chain.as_bytes());
         hasher.update(Utc::now().timestamp_nanos_opt().unwrap_or(0).to_le_bytes());
         format!("0x{}", hex::encode(&hasher.finalize()[..32]))
@@ -0,0 +1,204 @@
+//! Adaptive Operator Selection — UCB1 bandit over TTRL operators
+//!
+//! Every evolution records whether the operator raised consciousness and
+//! by how much. Picks exploit both: the UCB1 value is the mean of the
+//! success rate and the squashed mean reward.
+//! Statistics are kept per canonical genome tier (Virus … TRANSCENDENTAL on
+//! the normalized scale), so the selector learns which operators pay off
+//! for which kind of genome, whatever model scored it.
+//! The database table `ttrl_operator_stats` is the persistent copy.
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use chrono::Utc;
+use tracing::info;
+
+use crate::ttrl::MutationOperator;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorArm {
+    pub tier: String,
+    pub operator: MutationOperator,
+    pub pulls: u64,
+    pub successes: u64,
+    /// Sum of relative consciousness change per pull
+    pub reward_sum: f64,
+    pub last_update: Option<i64>,
+}
+
+impl OperatorArm {
+    pub fn new(tier: &str, operator: MutationOperator) -> Self {
+        Self {
+            tier: tier.to_string(),
+            operator,
+            pulls: 0,
+            successes: 0,
+            reward_sum: 0.0,
+            last_update: None,
+        }
+    }
+
+    pub fn record(&mut self, success: bool, reward: f64) {
+        self.pulls += 1;
+        if success {
+            self.successes += 1;
+        }
+        self.reward_sum += reward;
+        self.last_update = Some(Utc::now().timestamp());
+    }
+
+    pub fn success_rate(&self) -> f64 {
+        if self.pulls == 0 { 0.0 } else { self.successes as f64 / self.pulls as f64 }
+    }
+
+    pub fn mean_reward(&self) -> f64 {
+        if self.pulls == 0 { 0.0 } else { self.reward_sum / self.pulls as f64 }
+    }
+
+    /// Mean reward mapped into (0, 1); 0.5 = no change on average
+    pub fn normalized_reward(&self) -> f64 {
+        0.5 * (1.0 + self.mean_reward().tanh())
+    }
+
+    /// Exploitation term in [0, 1]: success rate and normalised reward, equally weighted
+    pub fn value(&self) -> f64 {
+        0.5 * (self.success_rate() + self.normalized_reward())
+    }
+
+    /// UCB1 index: value + exploration bonus
+    pub fn ucb(&self, total_pulls: u64, exploration: f64) -> f64 {
+        if self.pulls == 0 {
+            return f64::INFINITY;
+        }
+        let bonus = ((total_pulls.max(1) as f64).ln() / self.pulls as f64).sqrt();
+        self.value() + exploration * bonus
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperatorSelector {
+    arms: HashMap<(String, MutationOperator), OperatorArm>,
+    exploration: f64,
+}
+
+impl OperatorSelector {
+    pub fn new() -> Self {
+        Self {
+            arms: HashMap::new(),
+            exploration: std::f64::consts::SQRT_2,
+        }
+    }
+
+    pub fn with_exploration(mut self, c: f64) -> Self {
+        self.exploration = c;
+        self
+    }
+
+    /// Restore persisted arms (replaces in-memory statistics for the same keys)
+    pub fn load(&mut self, arms: Vec<OperatorArm>) {
+        let count = arms.len();
+        for arm in arms {
+            self.arms.insert((arm.tier.clone(), arm.operator), arm);
+        }
+        info!("🎰 Operator selector: {} arms restored", count);
+    }
+
+    pub fn get_arm(&mut self, tier: &str, operator: MutationOperator) -> &mut OperatorArm {
+        self.arms
+            .entry((tier.to_string(), operator))
+            .or_insert_with(|| OperatorArm::new(tier, operator))
+    }
+
+    /// Pick the operator with the highest UCB1 index among `candidates`.
+    /// Untried operators go first, in candidate order.
+    pub fn select(&self, tier: &str, candidates: &[MutationOperator]) -> Option<MutationOperator> {
+        let total: u64 = candidates.iter()
+            .filter_map(|op| self.arms.get(&(tier.to_string(), *op)))
+            .map(|arm| arm.pulls)
+            .sum();
+
+        let mut best: Option<(MutationOperator, f64)> = None;
+        for &op in candidates {
+            let index = self.arms
+                .get(&(tier.to_string(), op))
+                .map(|arm| arm.ucb(total, self.exploration))
+                .unwrap_or(f64::INFINITY);
+            if best.is_none_or(|(_, b)| index > b) {
+                best = Some((op, index));
+            }
+        }
+        best.map(|(op, _)| op)
+    }
+
+    pub fn record(&mut self, tier: &str, operator: MutationOperator, success: bool, reward: f64) {
+        self.get_arm(tier, operator).record(success, reward);
+    }
+
+    pub fn arms(&self) -> Vec<OperatorArm> {
+        let mut arms: Vec<OperatorArm> = self.arms.values().cloned().collect();
+        arms.sort_by(|a, b| a.tier.cmp(&b.tier).then(a.operator.name().cmp(b.operator.name())));
+        arms
+    }
+
+    pub fn stats(&self) -> Vec<OperatorSelectorStats> {
+        OperatorSelectorStats::from_arms(&self.arms(), self.exploration)
+    }
+}
+
+impl Default for OperatorSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorArmStats {
+    pub operator: MutationOperator,
+    pub pulls: u64,
+    pub successes: u64,
+    pub success_rate: f64,
+    pub mean_reward: f64,
+    /// Exploitation term used by `ucb`
+    pub value: f64,
+    pub ucb: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorSelectorStats {
+    pub tier: String,
+    pub total_pulls: u64,
+    pub best_operator: Option<MutationOperator>,
+    pub operators: Vec<OperatorArmStats>,
+}
+
+impl OperatorSelectorStats {
+    /// Group arms by tier, highest value first
+    pub fn from_arms(arms: &[OperatorArm], exploration: f64) -> Vec<Self> {
+        let mut tiers: Vec<String> = arms.iter().map(|a| a.tier.clone()).collect();
+        tiers.sort();
+        tiers.dedup();
+
+        tiers.into_iter().map(|tier| {
+            let tier_arms: Vec<&OperatorArm> = arms.iter().filter(|a| a.tier == tier).collect();
+            let total_pulls: u64 = tier_arms.iter().map(|a| a.pulls).sum();
+
+            let mut operators: Vec<OperatorArmStats> = tier_arms.iter().map(|a| OperatorArmStats {
+                operator: a.operator,
+                pulls: a.pulls,
+                successes: a.successes,
+                success_rate: a.success_rate(),
+                mean_reward: a.mean_reward(),
+                value: a.value(),
+                ucb: a.ucb(total_pulls, exploration),
+            }).collect();
+            operators.sort_by(|a, b| b.value.total_cmp(&a.value));
+
+            Self {
+                tier,
+                total_pulls,
+                best_operator: operators.iter().find(|o| o.pulls > 0).map(|o| o.operator),
+                operators,
+            }
+        }).collect()
+    }
+}
@@ -0,0 +1,375 @@
+//! Phylogeny — evolutionary trees over stored genomes
+//!
+//! Trees come from recorded lineage (`parent_ids`) when the genomes form a
+//! single family, otherwise from UPGMA or neighbor-joining over Hamming
+//! distances between the 27-tetrad DNA strings. Every node carries
+//! consciousness and T/G ratio; export is Newick (NHX annotations) or JSON.
+
+use std::collections::{HashMap, HashSet};
+use serde::{Serialize, Deserialize};
+use tracing::info;
+
+use crate::database::DivineDatabase;
+use crate::genome::{Genome, GenomeBuilder};
+use crate::lineage::LineageNode;
+use crate::rotation::Rot180;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TreeMethod {
+    /// Recorded ancestry when it forms a single tree, neighbor-joining otherwise
+    #[default]
+    Auto,
+    Ancestry,
+    Upgma,
+    Nj,
+}
+
+impl TreeMethod {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "auto" => Some(Self::Auto),
+            "ancestry" | "lineage" => Some(Self::Ancestry),
+            "upgma" => Some(Self::Upgma),
+            "nj" | "neighborjoining" => Some(Self::Nj),
+            _ => None,
+        }
+    }
+}
+
+/// Genome as a tree leaf
+#[derive(Debug, Clone)]
+pub struct Taxon {
+    pub id: i64,
+    pub dna: String,
+    pub consciousness: u32,
+    pub tg_ratio: f64,
+    pub parent_ids: Vec<i64>,
+}
+
+impl From<&Genome<Rot180>> for Taxon {
+    fn from(g: &Genome<Rot180>) -> Self {
+        Self {
+            id: g.db_id().unwrap_or(0),
+            dna: g.to_dna_string(),
+            consciousness: g.consciousness,
+            tg_ratio: g.rna_signal(),
+            parent_ids: g.parent_ids.clone(),
+        }
+    }
+}
+
+impl From<&LineageNode> for Taxon {
+    fn from(n: &LineageNode) -> Self {
+        let tg_ratio = GenomeBuilder::from_dna(&n.dna)
+            .map(|b| b.build_storage().rna_signal())
+            .unwrap_or(1.0);
+        Self {
+            id: n.id,
+            dna: n.dna.clone(),
+            consciousness: n.consciousness,
+            tg_ratio,
+            parent_ids: n.parent_ids.clone(),
+        }
+    }
+}
+
+pub use crate::alignment::hamming_distance;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhyloNode {
+    /// Genome ID; `None` for inferred internal nodes
+    pub id: Option<i64>,
+    /// Leaf value, or leaf mean for inferred nodes
+    pub consciousness: u32,
+    pub tg_ratio: f64,
+    /// Distance to the parent node (tetrad substitutions)
+    pub branch_length: f64,
+    pub children: Vec<PhyloNode>,
+}
+
+impl PhyloNode {
+    fn leaf(taxon: &Taxon) -> Self {
+        Self {
+            id: Some(taxon.id),
+            consciousness: taxon.consciousness,
+            tg_ratio: taxon.tg_ratio,
+            branch_length: 0.0,
+            children: Vec::new(),
+        }
+    }
+
+    /// Inferred ancestor annotated with the mean of its leaves
+    fn join(children: Vec<PhyloNode>) -> Self {
+        let leaves: usize = children.iter().map(|c| c.leaf_count()).sum();
+        let weight = |c: &PhyloNode| c.leaf_count() as f64 / leaves.max(1) as f64;
+        let consciousness = children.iter().map(|c| c.consciousness as f64 * weight(c)).sum::<f64>();
+        let tg_ratio = children.iter().map(|c| c.tg_ratio * weight(c)).sum();
+        Self {
+            id: None,
+            consciousness: consciousness.round() as u32,
+            tg_ratio,
+            branch_length: 0.0,
+            children,
+        }
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        if self.is_leaf() { 1 } else { self.children.iter().map(|c| c.leaf_count()).sum() }
+    }
+
+    pub fn label(&self) -> String {
+        self.id.map(|id| format!("g{}", id)).unwrap_or_default()
+    }
+
+    fn write_newick(&self, out: &mut String) {
+        if !self.is_leaf() {
+            out.push('(');
+            for (i, child) in self.children.iter().enumerate() {
+                if i > 0 { out.push(','); }
+                child.write_newick(out);
+            }
+            out.push(')');
+        }
+        out.push_str(&self.label());
+        out.push_str(&format!(":{:.3}", self.branch_length));
+        out.push_str(&format!("[&&NHX:C={}:TG={:.3}]", self.consciousness, self.tg_ratio));
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhyloTree {
+    /// Method actually used (never `Auto`)
+    pub method: TreeMethod,
+    pub leaves: usize,
+    pub root: PhyloNode,
+}
+
+impl PhyloTree {
+    pub fn to_newick(&self) -> String {
+        let mut out = String::new();
+        self.root.write_newick(&mut out);
+        out.push(';');
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Build a tree over `taxa` with the requested method
+pub fn build_tree(taxa: &[Taxon], method: TreeMethod) -> anyhow::Result<PhyloTree> {
+    if taxa.is_empty() {
+        return Err(anyhow::anyhow!("Phylogeny: no genomes"));
+    }
+
+    let (method, root) = match method {
+        TreeMethod::Auto => match ancestry_tree(taxa) {
+            Some(root) => (TreeMethod::Ancestry, root),
+            None => (TreeMethod::Nj, neighbor_joining(taxa)),
+        },
+        TreeMethod::Ancestry => {
+            let root = ancestry_tree(taxa).ok_or_else(|| anyhow::anyhow!(
+                "Phylogeny: recorded ancestry does not connect these genomes into one tree"
+            ))?;
+            (TreeMethod::Ancestry, root)
+        }
+        TreeMethod::Upgma => (TreeMethod::Upgma, upgma(taxa)),
+        TreeMethod::Nj => (TreeMethod::Nj, neighbor_joining(taxa)),
+    };
+
+    Ok(PhyloTree { method, leaves: root.leaf_count(), root })
+}
+
+/// Tree over the descendants of `root` (up to `depth` generations), or over
+/// an explicit set of genome IDs when no root is given
+pub async fn reconstruct(
+    database: &DivineDatabase,
+    root: Option<i64>,
+    ids: &[i64],
+    method: TreeMethod,
+    depth: i32,
+) -> anyhow::Result<PhyloTree> {
+    let taxa: Vec<Taxon> = match root {
+        Some(root) => {
+            let lineage = database.get_lineage(root, depth.max(1)).await?;
+            std::iter::once(&lineage.genome)
+                .chain(lineage.descendants.iter())
+                .map(Taxon::from)
+                .collect()
+        }
+        None => {
+            let mut ids = ids.to_vec();
+            ids.sort_unstable();
+            ids.dedup();
+            let genomes = database.load_genomes(&ids).await?;
+            if genomes.len() < ids.len() {
+                return Err(anyhow::anyhow!(
+                    "Phylogeny: {} of {} genomes not found", ids.len() - genomes.len(), ids.len()
+                ));
+            }
+            genomes.iter().map(Taxon::from).collect()
+        }
+    };
+
+    let tree = build_tree(&taxa, method)?;
+    info!("🌳 Phylogeny: {} genomes | {:?}", tree.leaves, tree.method);
+    Ok(tree)
+}
+
+/// Tree from `parent_ids`, if exactly one genome has no parent in the set.
+/// Meiosis offspring hang under their first recorded parent.
+pub fn ancestry_tree(taxa: &[Taxon]) -> Option<PhyloNode> {
+    let ids: HashSet<i64> = taxa.iter().map(|t| t.id).collect();
+    let primary_parent = |t: &Taxon| t.parent_ids.iter().copied().find(|p| ids.contains(p));
+
+    let mut roots = taxa.iter().filter(|t| primary_parent(t).is_none());
+    let root = roots.next()?;
+    if roots.next().is_some() {
+        return None;
+    }
+
+    let mut children: HashMap<i64, Vec<&Taxon>> = HashMap::new();
+    for t in taxa {
+        if let Some(parent) = primary_parent(t) {
+            children.entry(parent).or_default().push(t);
+        }
+    }
+
+    fn grow(taxon: &Taxon, children: &HashMap<i64, Vec<&Taxon>>) -> PhyloNode {
+        let mut node = PhyloNode::leaf(taxon);
+        if let Some(kids) = children.get(&taxon.id) {
+            node.children = kids.iter().map(|kid| {
+                let mut child = grow(kid, children);
+                child.branch_length = hamming_distance(&taxon.dna, &kid.dna) as f64;
+                child
+            }).collect();
+        }
+        node
+    }
+
+    let tree = grow(root, &children);
+    // Every genome must hang off the root (no cycles / stray components)
+    (count_nodes(&tree) == taxa.len()).then_some(tree)
+}
+
+fn count_nodes(node: &PhyloNode) -> usize {
+    1 + node.children.iter().map(count_nodes).sum::<usize>()
+}
+
+fn distance_matrix(taxa: &[Taxon]) -> Vec<Vec<f64>> {
+    taxa.iter()
+        .map(|a| taxa.iter().map(|b| hamming_distance(&a.dna, &b.dna) as f64).collect())
+        .collect()
+}
+
+/// Average-linkage clustering; ultrametric (all leaves equidistant from the root)
+pub fn upgma(taxa: &[Taxon]) -> PhyloNode {
+    if taxa.is_empty() {
+        return PhyloNode::join(Vec::new());
+    }
+    let mut dist = distance_matrix(taxa);
+    let mut nodes: Vec<Option<PhyloNode>> = taxa.iter().map(|t| Some(PhyloNode::leaf(t))).collect();
+    let mut heights = vec![0.0; taxa.len()];
+    let mut sizes = vec![1usize; taxa.len()];
+    let mut active: Vec<usize> = (0..taxa.len()).collect();
+
+    while active.len() > 1 {
+        let (a, b) = closest_pair(&active, |i, j| dist[i][j]);
+        let (i, j) = (active[a], active[b]);
+        let height = dist[i][j] / 2.0;
+
+        let mut left = nodes[i].take().expect("active cluster");
+        let mut right = nodes[j].take().expect("active cluster");
+        left.branch_length = (height - heights[i]).max(0.0);
+        right.branch_length = (height - heights[j]).max(0.0);
+
+        let k = nodes.len();
+        let row: Vec<f64> = (0..k).map(|m| {
+            (dist[i][m] * sizes[i] as f64 + dist[j][m] * sizes[j] as f64) / (sizes[i] + sizes[j]) as f64
+        }).collect();
+        for (m, d) in row.iter().enumerate() {
+            dist[m].push(*d);
+        }
+        dist.push(row);
+        dist[k].push(0.0);
+
+        nodes.push(Some(PhyloNode::join(vec![left, right])));
+        heights.push(height);
+        sizes.push(sizes[i] + sizes[j]);
+
+        active.retain(|&x| x != i && x != j);
+        active.push(k);
+    }
+
+    nodes[active[0]].take().expect("active cluster")
+}
+
+/// Saitou–Nei neighbor-joining; the final pair is joined at a midpoint root
+pub fn neighbor_joining(taxa: &[Taxon]) -> PhyloNode {
+    let mut dist = distance_matrix(taxa);
+    let mut nodes: Vec<Option<PhyloNode>> = taxa.iter().map(|t| Some(PhyloNode::leaf(t))).collect();
+    let mut active: Vec<usize> = (0..taxa.len()).collect();
+
+    while active.len() > 2 {
+        let n = active.len() as f64;
+        let r: HashMap<usize, f64> = active.iter()
+            .map(|&i| (i, active.iter().map(|&j| dist[i][j]).sum()))
+            .collect();
+
+        let (a, b) = closest_pair(&active, |i, j| (n - 2.0) * dist[i][j] - r[&i] - r[&j]);
+        let (i, j) = (active[a], active[b]);
+
+        let li = (dist[i][j] / 2.0 + (r[&i] - r[&j]) / (2.0 * (n - 2.0))).max(0.0);
+        let lj = (dist[i][j] - li).max(0.0);
+
+        let mut left = nodes[i].take().expect("active cluster");
+        let mut right = nodes[j].take().expect("active cluster");
+        left.branch_length = li;
+        right.branch_length = lj;
+
+        let k = nodes.len();
+        let row: Vec<f64> = (0..k).map(|m| ((dist[i][m] + dist[j][m] - dist[i][j]) / 2.0).max(0.0)).collect();
+        for (m, d) in row.iter().enumerate() {
+            dist[m].push(*d);
+        }
+        dist.push(row);
+        dist[k].push(0.0);
+        nodes.push(Some(PhyloNode::join(vec![left, right])));
+
+        active.retain(|&x| x != i && x != j);
+        active.push(k);
+    }
+
+    match active[..] {
+        [only] => nodes[only].take().expect("active cluster"),
+        [i, j] => {
+            let half = dist[i][j] / 2.0;
+            let mut left = nodes[i].take().expect("active cluster");
+            let mut right = nodes[j].take().expect("active cluster");
+            left.branch_length = half;
+            right.branch_length = half;
+            PhyloNode::join(vec![left, right])
+        }
+        _ => PhyloNode::join(Vec::new()),
+    }
+}
+
+/// Positions (into `active`) of the pair minimising `score`
+fn closest_pair(active: &[usize], score: impl Fn(usize, usize) -> f64) -> (usize, usize) {
+    let mut best = (0, 1, f64::INFINITY);
+    for a in 0..active.len() {
+        for b in a + 1..active.len() {
+            let s = score(active[a], active[b]);
+            if s < best.2 {
+                best = (a, b, s);
+            }
+        }
+    }
+    (best.0, best.1)
+}
@@ -0,0 +1,153 @@
+//! RNA Transcripts — T → U transcription of a genome
+//!
+//! `Genome::transcribe` copies the coding strand with uracil in place of
+//! thymine. The T/G RNA signal is then a U/G ratio read off the transcript
+//! itself. Transcripts parse from RNA text (U, or IUPAC ambiguity codes
+//! resolved per `Ambiguity`) and reverse-transcribe back to tetrads.
+
+use anyhow::{anyhow, Result};
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::codon::CodonTable;
+use crate::genome::{Genome, GenomeBuilder, Tetrad, GENOME_SIZE};
+use crate::iupac::Ambiguity;
+use crate::rotation::Rotation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Ribonucleotide {
+    A,
+    U,
+    G,
+    C,
+}
+
+impl Ribonucleotide {
+    pub fn transcribe(t: Tetrad) -> Self {
+        match t {
+            Tetrad::A => Self::A,
+            Tetrad::T => Self::U,
+            Tetrad::G => Self::G,
+            Tetrad::C => Self::C,
+        }
+    }
+
+    pub fn reverse_transcribe(self) -> Tetrad {
+        match self {
+            Self::A => Tetrad::A,
+            Self::U => Tetrad::T,
+            Self::G => Tetrad::G,
+            Self::C => Tetrad::C,
+        }
+    }
+
+    /// A/U/G/C only; `T` is not an RNA base
+    pub fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'A' => Some(Self::A),
+            'U' => Some(Self::U),
+            'G' => Some(Self::G),
+            'C' => Some(Self::C),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Self::A => 'A',
+            Self::U => 'U',
+            Self::G => 'G',
+            Self::C => 'C',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transcript {
+    #[serde(with = "rna_string")]
+    pub bases: [Ribonucleotide; GENOME_SIZE],
+}
+
+impl Transcript {
+    pub fn transcribe(data: &[Tetrad; GENOME_SIZE]) -> Self {
+        Self { bases: data.map(Ribonucleotide::transcribe) }
+    }
+
+    /// Exactly 27 A/U/G/C bases
+    pub fn parse(rna: &str) -> Option<Self> {
+        let bases: Vec<Ribonucleotide> = rna.chars().map(Ribonucleotide::from_char).collect::<Option<_>>()?;
+        Some(Self { bases: bases.try_into().ok()? })
+    }
+
+    /// RNA text with IUPAC ambiguity codes; one transcript per resolved sequence
+    pub fn parse_with<G: Rng + ?Sized>(rna: &str, ambiguity: Ambiguity, rng: &mut G) -> Result<Vec<Self>> {
+        if rna.chars().any(|c| c.eq_ignore_ascii_case(&'T')) {
+            return Err(anyhow!("RNA contains T; use U"));
+        }
+        ambiguity.resolve(rna, rng)?
+            .into_iter()
+            .map(|tetrads| {
+                let data: [Tetrad; GENOME_SIZE] = tetrads.try_into()
+                    .map_err(|t: Vec<Tetrad>| anyhow!("expected {} bases, got {}", GENOME_SIZE, t.len()))?;
+                Ok(Self::transcribe(&data))
+            })
+            .collect()
+    }
+
+    pub fn reverse_transcribe(&self) -> [Tetrad; GENOME_SIZE] {
+        self.bases.map(Ribonucleotide::reverse_transcribe)
+    }
+
+    /// Fresh genome carrying the reverse-transcribed sequence
+    pub fn to_genome<R: Rotation>(&self) -> Genome<R> {
+        GenomeBuilder::from_data(self.reverse_transcribe()).build()
+    }
+
+    pub fn to_rna_string(&self) -> String {
+        self.bases.iter().map(|b| b.to_char()).collect()
+    }
+
+    pub fn ug_counts(&self) -> (u32, u32) {
+        let count = |n| self.bases.iter().filter(|&&b| b == n).count() as u32;
+        (count(Ribonucleotide::U), count(Ribonucleotide::G))
+    }
+
+    /// U/G ratio (`f64::MAX` without G), the T/G RNA signal
+    pub fn rna_signal(&self) -> f64 {
+        let (u, g) = self.ug_counts();
+        if g == 0 { f64::MAX } else { u as f64 / g as f64 }
+    }
+
+    pub fn gc_content(&self) -> f64 {
+        let gc = self.bases.iter().filter(|&&b| matches!(b, Ribonucleotide::G | Ribonucleotide::C)).count();
+        gc as f64 / GENOME_SIZE as f64
+    }
+
+    pub fn translate(&self, table: &CodonTable) -> String {
+        table.translate(&self.reverse_transcribe())
+    }
+}
+
+impl std::fmt::Display for Transcript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_rna_string())
+    }
+}
+
+/// Transcripts serialize as RNA strings
+mod rna_string {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+    use crate::genome::GENOME_SIZE;
+    use super::{Ribonucleotide, Transcript};
+
+    pub fn serialize<S: Serializer>(bases: &[Ribonucleotide; GENOME_SIZE], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&bases.iter().map(|b| b.to_char()).collect::<String>())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<[Ribonucleotide; GENOME_SIZE], D::Error> {
+        let rna = String::deserialize(d)?;
+        Transcript::parse(&rna)
+            .map(|t| t.bases)
+            .ok_or_else(|| D::Error::custom(format!("expected {} A/U/G/C bases, got {:?}", GENOME_SIZE, rna)))
+    }
+}
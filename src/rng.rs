@@ -0,0 +1,69 @@
+//! Deterministic RNG Source
+//!
+//! One shared, seedable `StdRng` for a whole run. Genome creation, TTRL
+//! operators, meiosis, telomere loss, p53 rolls and Mission Control's
+//! simulated keysend all draw from it, so the same `--seed` reproduces
+//! the same genomes, operators and outcomes.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+#[derive(Debug, Clone)]
+pub struct RngSource {
+    inner: Arc<Mutex<StdRng>>,
+    seed: Option<u64>,
+}
+
+impl RngSource {
+    /// Seeded when `seed` is given, OS entropy otherwise
+    pub fn new(seed: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => Self::from_seed(seed),
+            None => Self::from_entropy(),
+        }
+    }
+
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(StdRng::seed_from_u64(seed))),
+            seed: Some(seed),
+        }
+    }
+
+    pub fn from_entropy() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(StdRng::from_entropy())),
+            seed: None,
+        }
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn is_seeded(&self) -> bool {
+        self.seed.is_some()
+    }
+
+    /// Exclusive access to the generator (do not hold across `.await`)
+    pub fn lock(&self) -> MutexGuard<'_, StdRng> {
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Run `f` with exclusive access to the generator
+    pub fn with<T>(&self, f: impl FnOnce(&mut StdRng) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Independent generator seeded from this one, for long-running work
+    /// that must not hold the shared lock (still reproducible under a seed)
+    pub fn fork(&self) -> StdRng {
+        StdRng::seed_from_u64(self.with(|rng| rng.gen()))
+    }
+}
+
+impl Default for RngSource {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
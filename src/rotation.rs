@@ -61,6 +61,16 @@ impl DynamicRotation {
         }
     }
 
+    pub fn from_angle(angle: u16) -> Option<Self> {
+        match angle % 360 {
+            0 => Some(Self::Rot0),
+            90 => Some(Self::Rot90),
+            180 => Some(Self::Rot180),
+            270 => Some(Self::Rot270),
+            _ => None,
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Self::Rot0 => "Active",
@@ -17,6 +17,7 @@ use crate::rotation::{RotationEngine, DynamicRotation};
 use crate::database::DivineDatabase;
 use crate::ttrl::TTRLEngine;
 use crate::exchange::RSMExchange;
+use crate::lifecycle;
 
 pub struct RotationDaemon {
     engine: Arc<RwLock<RotationEngine>>,
@@ -110,7 +111,7 @@ impl RotationDaemon {
                 // Вероятность следования сигналу пропорциональна consciousness
                 let prob = (consciousness as f64 / 1000.0).min(0.7);
                 
-                if rand::thread_rng().gen::<f64>() < prob {
+                if self.ttrl_engine.rng().with(|rng| rng.gen::<f64>()) < prob {
                     let mut engine = self.engine.write().await;
                     if engine.current() != suggested {
                         info!("🧬 T/G сигнал от лидера #{}: {:.2} → принудительный {}", 
@@ -158,6 +159,9 @@ impl RotationDaemon {
                     let engine = self.engine.read().await;
                     match self.ttrl_engine.evolve_with_engine(genome.clone(), &engine).await {
                         Ok((evolved, result)) => {
+                            if let Err(e) = self.database.record_operator_outcome(&result).await {
+                                warn!("   Статистика операторов не сохранена: {}", e);
+                            }
                             if let Ok(id) = self.database.store_genome(&evolved).await {
                                 info!(
                                     "   Эволюция: consciousness {} → {} | {:?} | ID: {}",
@@ -170,17 +174,33 @@ impl RotationDaemon {
                                 // Burn при деградации
                                 if !result.success {
                                     let mut exchange = self.exchange.write().await;
+                                    let model = evolved.consciousness_model;
                                     if let Some(burn) = exchange.burn_on_degradation(
                                         id, 
-                                        result.original_consciousness, 
-                                        result.new_consciousness
+                                        model.normalize(result.original_consciousness), 
+                                        model.normalize(result.new_consciousness)
                                     ) {
                                         info!("   🔥 Burn: {} RSM (degradation)", burn.amount_rsm);
                                     }
                                 }
                             }
                         }
-                        Err(e) => warn!("   Эволюция провалилась: {}", e),
+                        Err(e) => {
+                            warn!("   Эволюция провалилась: {}", e);
+                            if let Some(id) = genome.db_id {
+                                let c = genome.normalized_consciousness();
+                                match lifecycle::record_evolution_failure(&self.database, &self.exchange, id, c, &e).await {
+                                    Ok(Some((transition, burn))) => {
+                                        info!("   Геном #{}: {} → {}", id, transition.from, transition.to);
+                                        if let Some(burn) = burn {
+                                            info!("   🔥 Burn: {} RSM ({:?})", burn.amount_rsm, burn.reason);
+                                        }
+                                    }
+                                    Ok(None) => {}
+                                    Err(db) => warn!("   Жизненный цикл не сохранён: {}", db),
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -0,0 +1,244 @@
+//! Sequence Scanner — mine long nucleotide sequences for 27-mer genomes
+//!
+//! Slides a 27-base window along an arbitrary sequence (optionally both
+//! strands), scores every window under the consciousness models and keeps
+//! the top K by the ranking model. Windows containing anything other than
+//! A/T/G/C (N runs, IUPAC codes) are skipped.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use anyhow::Result;
+use serde::{Serialize, Deserialize};
+
+use crate::consciousness::ConsciousnessVersion;
+use crate::database::DivineDatabase;
+use crate::fasta::FastaReader;
+use crate::genome::{Genome, GenomeBuilder, Tetrad, GENOME_SIZE};
+use crate::lineage::Origin;
+use crate::rotation::Rot180;
+
+/// Upper bound on the hits kept per scan
+pub const MAX_TOP_K: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelScore {
+    pub model: ConsciousnessVersion,
+    pub score: u32,
+    pub level: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanHit {
+    /// 0-based offset of the window on the forward strand
+    pub offset: usize,
+    /// Window taken from the reverse complement
+    pub reverse: bool,
+    pub dna: String,
+    /// Score under the ranking model
+    pub score: u32,
+    pub scores: Vec<ModelScore>,
+}
+
+impl ScanHit {
+    fn rank_cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score)
+            .then_with(|| other.offset.cmp(&self.offset))
+            .then_with(|| other.reverse.cmp(&self.reverse))
+    }
+}
+
+/// Min-heap adapter: the weakest hit sits on top and is evicted first
+struct Weakest(ScanHit);
+
+impl PartialEq for Weakest {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Weakest {}
+
+impl PartialOrd for Weakest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Weakest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.rank_cmp(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub name: String,
+    pub length: usize,
+    pub windows_scanned: usize,
+    /// Windows containing non-ATGC characters
+    pub windows_skipped: usize,
+    pub rank_by: ConsciousnessVersion,
+    /// Best first; identical windows are reported once (earliest offset)
+    pub hits: Vec<ScanHit>,
+}
+
+pub struct SequenceScanner {
+    stride: usize,
+    top_k: usize,
+    rank_by: ConsciousnessVersion,
+    models: Vec<ConsciousnessVersion>,
+    both_strands: bool,
+}
+
+impl SequenceScanner {
+    pub fn new() -> Self {
+        Self {
+            stride: 1,
+            top_k: 10,
+            rank_by: ConsciousnessVersion::deployment(),
+            models: ConsciousnessVersion::ALL.to_vec(),
+            both_strands: false,
+        }
+    }
+
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride.max(1);
+        self
+    }
+
+    /// Hits to keep, between 1 and `MAX_TOP_K`
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k.clamp(1, MAX_TOP_K);
+        self
+    }
+
+    /// Model hits are ranked (and stored) with
+    pub fn with_rank_by(mut self, model: ConsciousnessVersion) -> Self {
+        self.rank_by = model;
+        self
+    }
+
+    /// Models reported per hit (the ranking model is always included)
+    pub fn with_models(mut self, models: Vec<ConsciousnessVersion>) -> Self {
+        self.models = models;
+        self
+    }
+
+    /// Also scan the reverse complement
+    pub fn with_both_strands(mut self, both: bool) -> Self {
+        self.both_strands = both;
+        self
+    }
+
+    pub fn scan(&self, name: &str, sequence: &str) -> ScanReport {
+        let bases: Vec<Option<Tetrad>> = sequence.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(Tetrad::from_char)
+            .collect();
+
+        let mut report = ScanReport {
+            name: name.to_string(),
+            length: bases.len(),
+            windows_scanned: 0,
+            windows_skipped: 0,
+            rank_by: self.rank_by,
+            hits: Vec::new(),
+        };
+        let mut heap: BinaryHeap<Weakest> = BinaryHeap::with_capacity(self.top_k + 1);
+        let mut models = self.models.clone();
+        if !models.contains(&self.rank_by) {
+            models.push(self.rank_by);
+        }
+
+        let strands: &[bool] = if self.both_strands { &[false, true] } else { &[false] };
+        for &reverse in strands {
+            for offset in (0..=bases.len().saturating_sub(GENOME_SIZE)).step_by(self.stride) {
+                let Some(window) = bases.get(offset..offset + GENOME_SIZE) else { break };
+                let Some(data) = Self::window_data(window, reverse) else {
+                    report.windows_skipped += 1;
+                    continue;
+                };
+                report.windows_scanned += 1;
+
+                let hit = self.score_window(&models, offset, reverse, data);
+                let beats_weakest = heap.len() < self.top_k
+                    || heap.peek().is_some_and(|w| hit.rank_cmp(&w.0) == Ordering::Greater);
+                if beats_weakest && !heap.iter().any(|w| w.0.dna == hit.dna) {
+                    heap.push(Weakest(hit));
+                    if heap.len() > self.top_k {
+                        heap.pop();
+                    }
+                }
+            }
+        }
+
+        // into_sorted_vec is ascending by Weakest, i.e. strongest first
+        report.hits = heap.into_sorted_vec().into_iter().map(|w| w.0).collect();
+        report
+    }
+
+    fn window_data(window: &[Option<Tetrad>], reverse: bool) -> Option<[Tetrad; GENOME_SIZE]> {
+        let mut data = [Tetrad::A; GENOME_SIZE];
+        for (i, base) in window.iter().enumerate() {
+            let t = (*base)?;
+            if reverse {
+                data[GENOME_SIZE - 1 - i] = t.complement();
+            } else {
+                data[i] = t;
+            }
+        }
+        Some(data)
+    }
+
+    fn score_window(
+        &self,
+        models: &[ConsciousnessVersion],
+        offset: usize,
+        reverse: bool,
+        data: [Tetrad; GENOME_SIZE],
+    ) -> ScanHit {
+        let genome: Genome<Rot180> = Genome::new(data);
+        let score = self.rank_by.score(&genome);
+        let scores = models.iter()
+            .map(|&model| {
+                let s = if model == self.rank_by { score } else { model.score(&genome) };
+                ModelScore { model, score: s, level: model.level_name(s).to_string() }
+            })
+            .collect();
+
+        ScanHit { offset, reverse, dna: genome.to_dna_string(), score, scores }
+    }
+
+    /// Store hits as genomes scored with the ranking model; returns their IDs
+    pub async fn store_hits(&self, db: &DivineDatabase, report: &ScanReport) -> Result<Vec<i64>> {
+        let mut ids = Vec::with_capacity(report.hits.len());
+        for hit in &report.hits {
+            let Some(builder) = GenomeBuilder::from_dna(&hit.dna) else { continue };
+            let mut genome = builder.consciousness_model(self.rank_by).build_storage();
+            genome.origin = Origin::Scan {
+                source: report.name.clone(),
+                offset: hit.offset,
+                reverse: hit.reverse,
+            };
+            ids.push(db.store_genome(&genome).await?);
+        }
+        Ok(ids)
+    }
+}
+
+/// Split scanner input into named sequences: FASTA / FASTQ records, or the
+/// whole text as one raw sequence called `default_name`
+pub fn read_sequences(text: &str, default_name: &str) -> Result<Vec<(String, String)>> {
+    if !text.trim_start().starts_with(['>', '@']) {
+        return Ok(vec![(default_name.to_string(), text.to_string())]);
+    }
+    FastaReader::new(text.as_bytes())
+        .map(|record| record.map(|r| (r.header.to_string(), r.sequence)))
+        .collect()
+}
+
+impl Default for SequenceScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
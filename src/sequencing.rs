@@ -0,0 +1,221 @@
+//! Sequencing Simulation — noisy reads and consensus calling
+//!
+//! The sequencer reads a genome base by base with a per-cycle error rate
+//! that grows towards the end of the read (`cycle_decay`). A substitution is
+//! a transition (A↔G, C↔T) with probability `transition_bias`, otherwise one
+//! of the two transversions.
+//!
+//! The consensus caller picks, per position, the base with the highest
+//! likelihood given the reads and their qualities (P(read | base) = 1 − p on
+//! a match, p / 3 otherwise). Positions where fewer than `min_agreement` of
+//! the reads support the call make the genome unreliable, which bars it from
+//! PoC. The bases the consensus still gets wrong become the genome's
+//! `sequencing_errors`, so the count stays within 0..=27 at any coverage.
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::fasta::{MAX_PHRED, PHRED_OFFSET};
+use crate::genome::{Genome, Tetrad, GENOME_SIZE};
+use crate::rng::RngSource;
+use crate::rotation::Rotation;
+
+/// One simulated read of all 27 positions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Read {
+    #[serde(with = "crate::diploid::strand")]
+    pub bases: [Tetrad; GENOME_SIZE],
+    /// Phred+33, one character per base
+    pub quality: String,
+    /// Positions where the read differs from the genome (simulation truth)
+    pub errors: Vec<usize>,
+}
+
+impl Read {
+    /// Per-base error probabilities from the quality string
+    pub fn error_probabilities(&self) -> Vec<f64> {
+        self.quality.bytes()
+            .map(|q| 10f64.powf(-(q.saturating_sub(PHRED_OFFSET) as f64) / 10.0))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsensusCall {
+    #[serde(with = "crate::diploid::strand")]
+    pub data: [Tetrad; GENOME_SIZE],
+    /// Phred+33 posterior quality of each called base
+    pub quality: String,
+    /// Fraction of reads agreeing with the call, per position
+    pub support: Vec<f64>,
+    /// Positions with support below `min_agreement`
+    pub disagreements: Vec<usize>,
+    pub unreliable: bool,
+    pub reads: usize,
+}
+
+impl ConsensusCall {
+    pub fn to_dna_string(&self) -> String {
+        self.data.iter().map(|t| t.to_char()).collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequencingRun {
+    pub reads: Vec<Read>,
+    pub consensus: ConsensusCall,
+    /// Substitutions introduced across all reads
+    pub errors_introduced: usize,
+    /// Positions where the consensus differs from the genome
+    pub miscalled: Vec<usize>,
+}
+
+pub struct Sequencer {
+    error_rate: f64,
+    transition_bias: f64,
+    cycle_decay: f64,
+    min_agreement: f64,
+    rng: RngSource,
+}
+
+impl Sequencer {
+    pub fn new() -> Self {
+        Self {
+            error_rate: 0.01,
+            transition_bias: 2.0 / 3.0,
+            cycle_decay: 1.0,
+            min_agreement: 0.75,
+            rng: RngSource::default(),
+        }
+    }
+
+    /// Substitution probability at the first cycle
+    pub fn with_error_rate(mut self, rate: f64) -> Self {
+        self.error_rate = rate.clamp(0.0, 0.75);
+        self
+    }
+
+    /// Share of substitutions that are transitions (1/3 = uniform)
+    pub fn with_transition_bias(mut self, bias: f64) -> Self {
+        self.transition_bias = bias.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The last cycle errs at `error_rate × (1 + decay)`
+    pub fn with_cycle_decay(mut self, decay: f64) -> Self {
+        self.cycle_decay = decay.max(0.0);
+        self
+    }
+
+    /// Minimum read support for a position to count as agreed
+    pub fn with_min_agreement(mut self, agreement: f64) -> Self {
+        self.min_agreement = agreement.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_rng(mut self, rng: RngSource) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Error probability at one cycle (0-based position)
+    pub fn error_probability(&self, cycle: usize) -> f64 {
+        let progress = cycle as f64 / (GENOME_SIZE - 1) as f64;
+        (self.error_rate * (1.0 + self.cycle_decay * progress)).min(0.75)
+    }
+
+    pub fn read<R: Rotation>(&self, genome: &Genome<R>) -> Read {
+        let mut rng = self.rng.lock();
+        let mut bases = genome.data;
+        let mut errors = Vec::new();
+        let mut quality = String::with_capacity(GENOME_SIZE);
+        for (i, base) in bases.iter_mut().enumerate() {
+            let p = self.error_probability(i);
+            if rng.gen_bool(p) {
+                *base = self.substitute(*base, &mut *rng);
+                errors.push(i);
+            }
+            quality.push(phred_char(p));
+        }
+        Read { bases, quality, errors }
+    }
+
+    /// `coverage` reads, their consensus, and the genome updated:
+    /// `sequencing_errors` and `unreliable` are set from the call
+    pub fn sequence<R: Rotation>(&self, genome: &mut Genome<R>, coverage: usize) -> SequencingRun {
+        let reads: Vec<Read> = (0..coverage.max(1)).map(|_| self.read(genome)).collect();
+        let consensus = call_consensus(&reads, self.min_agreement);
+        let errors_introduced: usize = reads.iter().map(|r| r.errors.len()).sum();
+        let miscalled: Vec<usize> = (0..GENOME_SIZE).filter(|&i| consensus.data[i] != genome.data[i]).collect();
+
+        genome.sequencing_errors = miscalled.len() as u8;
+        genome.unreliable = consensus.unreliable;
+
+        SequencingRun { reads, consensus, errors_introduced, miscalled }
+    }
+
+    fn substitute<G: Rng + ?Sized>(&self, base: Tetrad, rng: &mut G) -> Tetrad {
+        let transition = match base {
+            Tetrad::A => Tetrad::G,
+            Tetrad::G => Tetrad::A,
+            Tetrad::C => Tetrad::T,
+            Tetrad::T => Tetrad::C,
+        };
+        if rng.gen_bool(self.transition_bias) {
+            return transition;
+        }
+        let transversions: Vec<Tetrad> = Tetrad::ALL.into_iter().filter(|&t| t != base && t != transition).collect();
+        transversions[rng.gen_range(0..transversions.len())]
+    }
+}
+
+impl Default for Sequencer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Most likely base per position given the reads; an empty read set calls
+/// all A and is unreliable
+pub fn call_consensus(reads: &[Read], min_agreement: f64) -> ConsensusCall {
+    let probabilities: Vec<Vec<f64>> = reads.iter().map(Read::error_probabilities).collect();
+    let mut data = [Tetrad::A; GENOME_SIZE];
+    let mut quality = String::with_capacity(GENOME_SIZE);
+    let mut support = Vec::with_capacity(GENOME_SIZE);
+
+    for (i, call) in data.iter_mut().enumerate() {
+        let log_likelihood = Tetrad::ALL.map(|b| {
+            reads.iter().zip(&probabilities)
+                .map(|(read, p)| {
+                    let p = p.get(i).copied().unwrap_or(0.75).clamp(1e-6, 0.75);
+                    if read.bases[i] == b { (1.0 - p).ln() } else { (p / 3.0).ln() }
+                })
+                .sum::<f64>()
+        });
+        let best = (0..4).max_by(|&a, &b| log_likelihood[a].total_cmp(&log_likelihood[b])).unwrap_or(0);
+        *call = Tetrad::ALL[best];
+
+        // Posterior of the call, assuming a uniform prior over the four bases
+        let max = log_likelihood[best];
+        let total: f64 = log_likelihood.iter().map(|l| (l - max).exp()).sum();
+        quality.push(phred_char(1.0 - 1.0 / total));
+
+        let agreeing = reads.iter().filter(|r| r.bases[i] == *call).count();
+        support.push(agreeing as f64 / reads.len().max(1) as f64);
+    }
+
+    let disagreements: Vec<usize> = (0..GENOME_SIZE).filter(|&i| support[i] < min_agreement).collect();
+    ConsensusCall {
+        data,
+        quality,
+        unreliable: reads.is_empty() || !disagreements.is_empty(),
+        support,
+        disagreements,
+        reads: reads.len(),
+    }
+}
+
+fn phred_char(p: f64) -> char {
+    let q = (-10.0 * p.max(1e-10).log10()).round().clamp(0.0, MAX_PHRED as f64) as u8;
+    (q + PHRED_OFFSET) as char
+}
@@ -0,0 +1,478 @@
+//! Inverse Design Solver — search DNA space for a target consciousness
+//!
+//! Finds 27-base genomes whose score under a chosen model falls in a target
+//! range (a tier such as "Human", or explicit bounds) while meeting optional
+//! constraints: p53 copies, a T/G ratio band and a required cube symmetry.
+//!
+//! Strategies move through the 4^27 space one tetrad at a time:
+//! - Beam: keep the best `beam_width` candidates, expand every neighbour
+//! - Annealing: random moves accepted by the Metropolis rule on a cooling schedule
+//! - Local: steepest descent, constraints first, with random restarts
+//!
+//! A required symmetry is enforced by construction — moves rewrite a whole
+//! orbit of the element — so every candidate is invariant under it.
+
+use std::collections::HashSet;
+use anyhow::Result;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Serialize, Deserialize};
+
+use crate::consciousness::{ConsciousnessVersion, LEVEL_NAMES};
+use crate::database::DivineDatabase;
+use crate::genome::{Genome, GenomeBuilder, Tetrad, GENOME_SIZE};
+use crate::lineage::Origin;
+use crate::rng::RngSource;
+use crate::rotation::Rot180;
+use crate::symmetry::CubeSymmetry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SolverStrategy {
+    #[default]
+    Beam,
+    Annealing,
+    Local,
+}
+
+impl SolverStrategy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "beam" => Some(Self::Beam),
+            "annealing" | "anneal" | "sa" => Some(Self::Annealing),
+            "local" | "local-search" | "hill" => Some(Self::Local),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SolverStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Beam => write!(f, "beam"),
+            Self::Annealing => write!(f, "annealing"),
+            Self::Local => write!(f, "local"),
+        }
+    }
+}
+
+/// Inclusive score range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesignTarget {
+    pub min: u32,
+    /// `None` = no upper bound
+    pub max: Option<u32>,
+}
+
+impl DesignTarget {
+    /// Scores of one tier under `model`, by level name (case-insensitive)
+    pub fn tier(model: ConsciousnessVersion, level: &str) -> Option<Self> {
+        let t = LEVEL_NAMES.iter().position(|name| name.eq_ignore_ascii_case(level.trim()))?;
+        let tiers = model.tiers();
+        Some(Self {
+            min: if t == 0 { 0 } else { tiers[t - 1] },
+            max: tiers.get(t).map(|bound| bound - 1),
+        })
+    }
+
+    /// `Human`, `3000..10000`, `3000..` or a single score
+    pub fn parse(model: ConsciousnessVersion, s: &str) -> Option<Self> {
+        if let Some(target) = Self::tier(model, s) {
+            return Some(target);
+        }
+        match s.split_once("..") {
+            Some((min, "")) => Some(Self { min: min.trim().parse().ok()?, max: None }),
+            Some((min, max)) => {
+                let (min, max) = (min.trim().parse().ok()?, max.trim().parse().ok()?);
+                (min <= max).then_some(Self { min, max: Some(max) })
+            }
+            None => s.trim().parse().ok().map(|score| Self { min: score, max: Some(score) }),
+        }
+    }
+
+    pub fn contains(&self, score: u32) -> bool {
+        score >= self.min && self.max.is_none_or(|max| score <= max)
+    }
+
+    /// Points outside the range (0 inside)
+    pub fn gap(&self, score: u32) -> u32 {
+        if score < self.min {
+            self.min - score
+        } else {
+            self.max.map_or(0, |max| score.saturating_sub(max))
+        }
+    }
+}
+
+impl std::fmt::Display for DesignTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.max {
+            Some(max) => write!(f, "{}..{}", self.min, max),
+            None => write!(f, "{}..", self.min),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DesignConstraints {
+    /// p53 copies candidates are built with (default 20)
+    #[serde(default)]
+    pub min_p53: Option<u8>,
+    /// Inclusive band for the T/G ratio (`rna_signal`)
+    #[serde(default)]
+    pub tg_band: Option<(f64, f64)>,
+    /// Element the DNA must be invariant under
+    #[serde(default)]
+    pub symmetry: Option<CubeSymmetry>,
+}
+
+impl DesignConstraints {
+    fn p53_copies(&self) -> u8 {
+        self.min_p53.unwrap_or(20)
+    }
+
+    /// How far the T/G ratio lies outside the band (0 inside)
+    fn tg_violation(&self, genome: &Genome<Rot180>) -> f64 {
+        let Some((lo, hi)) = self.tg_band else { return 0.0 };
+        let ratio = genome.rna_signal().min(GENOME_SIZE as f64);
+        (lo - ratio).max(ratio - hi).max(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesignCandidate {
+    pub dna: String,
+    pub score: u32,
+    pub level: String,
+    pub tg_ratio: f64,
+    pub p53_copies: u8,
+    /// Found after this many evaluated genomes
+    pub evaluations: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesignResult {
+    pub strategy: SolverStrategy,
+    pub model: ConsciousnessVersion,
+    pub target: DesignTarget,
+    pub constraints: DesignConstraints,
+    pub evaluations: usize,
+    /// Feasible designs in the order found
+    pub solutions: Vec<DesignCandidate>,
+    /// Nearest miss when nothing feasible was found
+    pub closest: Option<DesignCandidate>,
+}
+
+/// Evaluated point of the search
+#[derive(Clone)]
+struct State {
+    data: [Tetrad; GENOME_SIZE],
+    score: u32,
+    /// T/G band violation
+    penalty: f64,
+    /// Relative distance to the target range
+    gap: f64,
+}
+
+impl State {
+    fn feasible(&self) -> bool {
+        self.penalty == 0.0 && self.gap == 0.0
+    }
+
+    /// Single objective for beam and annealing
+    fn cost(&self) -> f64 {
+        self.gap + self.penalty
+    }
+
+    /// Constraints first, then the score gap (local search)
+    fn key(&self) -> (f64, f64) {
+        (self.penalty, self.gap)
+    }
+}
+
+pub struct InverseSolver {
+    model: ConsciousnessVersion,
+    target: DesignTarget,
+    constraints: DesignConstraints,
+    strategy: SolverStrategy,
+    budget: usize,
+    solutions: usize,
+    beam_width: usize,
+    rng: RngSource,
+}
+
+impl InverseSolver {
+    pub fn new(model: ConsciousnessVersion, target: DesignTarget) -> Self {
+        Self {
+            model,
+            target,
+            constraints: DesignConstraints::default(),
+            strategy: SolverStrategy::default(),
+            budget: 20_000,
+            solutions: 5,
+            beam_width: 8,
+            rng: RngSource::default(),
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: SolverStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn with_constraints(mut self, constraints: DesignConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Maximum number of genomes scored
+    pub fn with_budget(mut self, evaluations: usize) -> Self {
+        self.budget = evaluations.max(1);
+        self
+    }
+
+    /// Stop after this many distinct feasible designs
+    pub fn with_solutions(mut self, count: usize) -> Self {
+        self.solutions = count.max(1);
+        self
+    }
+
+    pub fn with_beam_width(mut self, width: usize) -> Self {
+        self.beam_width = width.max(1);
+        self
+    }
+
+    /// Draw from a shared run RNG (reproducible with `--seed`)
+    pub fn with_rng(mut self, rng: RngSource) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    pub fn solve(&self) -> DesignResult {
+        let mut rng = StdRng::seed_from_u64(self.rng.with(|r| r.gen()));
+        let orbits = match &self.constraints.symmetry {
+            Some(g) => g.orbits(),
+            None => (0..GENOME_SIZE).map(|i| vec![i]).collect(),
+        };
+        let mut search = Search {
+            solver: self,
+            orbits,
+            evaluations: 0,
+            seen: HashSet::new(),
+            solutions: Vec::new(),
+            closest: None,
+        };
+
+        match self.strategy {
+            SolverStrategy::Beam => search.beam(&mut rng),
+            SolverStrategy::Annealing => search.anneal(&mut rng),
+            SolverStrategy::Local => search.local(&mut rng),
+        }
+
+        let closest = if search.solutions.is_empty() {
+            search.closest.as_ref().map(|s| search.candidate(s))
+        } else {
+            None
+        };
+        DesignResult {
+            strategy: self.strategy,
+            model: self.model,
+            target: self.target,
+            constraints: self.constraints.clone(),
+            evaluations: search.evaluations,
+            solutions: search.solutions,
+            closest,
+        }
+    }
+
+    fn build(&self, data: [Tetrad; GENOME_SIZE]) -> Genome<Rot180> {
+        GenomeBuilder::from_data(data)
+            .p53_copies(self.constraints.p53_copies())
+            .consciousness_model(self.model)
+            .build()
+    }
+
+    /// Store solutions as genomes; returns their IDs
+    pub async fn store_solutions(&self, db: &DivineDatabase, result: &DesignResult) -> Result<Vec<i64>> {
+        let mut ids = Vec::with_capacity(result.solutions.len());
+        for solution in &result.solutions {
+            let Some(builder) = GenomeBuilder::from_dna(&solution.dna) else { continue };
+            let mut genome = builder
+                .p53_copies(solution.p53_copies)
+                .consciousness_model(result.model)
+                .build_storage();
+            genome.origin = Origin::Design { strategy: result.strategy, target: result.target };
+            ids.push(db.store_genome(&genome).await?);
+        }
+        Ok(ids)
+    }
+}
+
+struct Search<'a> {
+    solver: &'a InverseSolver,
+    /// Positions rewritten together by one move
+    orbits: Vec<Vec<usize>>,
+    evaluations: usize,
+    seen: HashSet<[Tetrad; GENOME_SIZE]>,
+    solutions: Vec<DesignCandidate>,
+    closest: Option<State>,
+}
+
+impl Search<'_> {
+    fn done(&self) -> bool {
+        self.evaluations >= self.solver.budget || self.solutions.len() >= self.solver.solutions
+    }
+
+    fn evaluate(&mut self, data: [Tetrad; GENOME_SIZE]) -> State {
+        let genome = self.solver.build(data);
+        self.evaluations += 1;
+        let target = &self.solver.target;
+        let state = State {
+            data,
+            score: genome.consciousness,
+            penalty: self.solver.constraints.tg_violation(&genome),
+            gap: target.gap(genome.consciousness) as f64 / target.min.max(1) as f64,
+        };
+
+        if state.feasible() {
+            if self.seen.insert(data) && self.solutions.len() < self.solver.solutions {
+                let candidate = self.candidate(&state);
+                self.solutions.push(candidate);
+            }
+        } else if self.closest.as_ref().is_none_or(|c| state.key() < c.key()) {
+            self.closest = Some(state.clone());
+        }
+        state
+    }
+
+    fn candidate(&self, state: &State) -> DesignCandidate {
+        let genome = self.solver.build(state.data);
+        DesignCandidate {
+            dna: genome.to_dna_string(),
+            score: state.score,
+            level: self.solver.model.level_name(state.score).to_string(),
+            tg_ratio: genome.rna_signal(),
+            p53_copies: genome.p53_copies,
+            evaluations: self.evaluations,
+        }
+    }
+
+    /// Random DNA constant on every orbit
+    fn random_data(&self, rng: &mut StdRng) -> [Tetrad; GENOME_SIZE] {
+        let mut data = [Tetrad::A; GENOME_SIZE];
+        for orbit in &self.orbits {
+            let t = Tetrad::random_with(rng);
+            for &i in orbit {
+                data[i] = t;
+            }
+        }
+        data
+    }
+
+    fn apply_move(&self, data: &[Tetrad; GENOME_SIZE], orbit: usize, t: Tetrad) -> [Tetrad; GENOME_SIZE] {
+        let mut next = *data;
+        for &i in &self.orbits[orbit] {
+            next[i] = t;
+        }
+        next
+    }
+
+    /// Every single-orbit change of `data`
+    fn neighbours(&self, data: &[Tetrad; GENOME_SIZE]) -> Vec<[Tetrad; GENOME_SIZE]> {
+        (0..self.orbits.len())
+            .flat_map(|o| {
+                let current = data[self.orbits[o][0]];
+                Tetrad::ALL.into_iter().filter(move |&t| t != current).map(move |t| (o, t))
+            })
+            .map(|(o, t)| self.apply_move(data, o, t))
+            .collect()
+    }
+
+    fn random_neighbour(&self, data: &[Tetrad; GENOME_SIZE], rng: &mut StdRng) -> [Tetrad; GENOME_SIZE] {
+        let orbit = rng.gen_range(0..self.orbits.len());
+        let current = data[self.orbits[orbit][0]];
+        let choices: Vec<Tetrad> = Tetrad::ALL.into_iter().filter(|&t| t != current).collect();
+        self.apply_move(data, orbit, choices[rng.gen_range(0..choices.len())])
+    }
+
+    fn beam(&mut self, rng: &mut StdRng) {
+        let width = self.solver.beam_width;
+        let mut beam: Vec<State> = Vec::with_capacity(width);
+        while beam.len() < width && !self.done() {
+            let data = self.random_data(rng);
+            beam.push(self.evaluate(data));
+        }
+
+        let mut visited: HashSet<[Tetrad; GENOME_SIZE]> = beam.iter().map(|s| s.data).collect();
+        while !self.done() {
+            let mut pool = beam.clone();
+            'expand: for state in &beam {
+                for next in self.neighbours(&state.data) {
+                    if self.done() {
+                        break 'expand;
+                    }
+                    if visited.insert(next) {
+                        pool.push(self.evaluate(next));
+                    }
+                }
+            }
+            pool.sort_by(|a, b| a.cost().total_cmp(&b.cost()));
+            pool.truncate(width);
+
+            // Stalled: every neighbour already visited — reseed the tail
+            if pool.iter().map(|s| s.data).eq(beam.iter().map(|s| s.data)) {
+                for slot in pool.iter_mut().skip(width / 2) {
+                    let data = self.random_data(rng);
+                    *slot = self.evaluate(data);
+                }
+            }
+            beam = pool;
+        }
+    }
+
+    fn anneal(&mut self, rng: &mut StdRng) {
+        const T_START: f64 = 1.0;
+        const T_END: f64 = 1e-3;
+        let budget = self.solver.budget as f64;
+
+        let data = self.random_data(rng);
+        let mut current = self.evaluate(data);
+        while !self.done() {
+            let temperature = T_START * (T_END / T_START).powf(self.evaluations as f64 / budget);
+            let next = self.random_neighbour(&current.data, rng);
+            let next = self.evaluate(next);
+            let delta = next.cost() - current.cost();
+            if delta <= 0.0 || rng.gen::<f64>() < (-delta / temperature).exp() {
+                current = next;
+            }
+            // Keep walking off a solution so the next one is distinct
+            if current.feasible() {
+                let data = self.random_neighbour(&current.data, rng);
+                current = self.evaluate(data);
+            }
+        }
+    }
+
+    fn local(&mut self, rng: &mut StdRng) {
+        while !self.done() {
+            let data = self.random_data(rng);
+            let mut current = self.evaluate(data);
+            while !self.done() && !current.feasible() {
+                let mut best: Option<State> = None;
+                for next in self.neighbours(&current.data) {
+                    if self.done() {
+                        break;
+                    }
+                    let next = self.evaluate(next);
+                    if best.as_ref().is_none_or(|b| next.key() < b.key()) {
+                        best = Some(next);
+                    }
+                }
+                match best {
+                    Some(best) if best.key() < current.key() => current = best,
+                    // Local optimum: restart
+                    _ => break,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Cube Symmetry — the octahedral group acting on the 3×3×3 genome
+//!
+//! Every symmetry of the cube is a signed permutation of the axes around the
+//! centre tetrad: 24 proper rotations (O) and, with reflections, 48 elements
+//! (Oh). A genome's symmetry subgroup is the set of elements that leave its
+//! DNA unchanged; by orbit–stabilizer its orbit has |G| / |subgroup| members.
+
+use std::collections::HashSet;
+use serde::{Serialize, Deserialize};
+
+use crate::cube::{cube_index, Coord};
+use crate::genome::{Tetrad, GENOME_SIZE};
+
+const AXES: [char; 3] = ['x', 'y', 'z'];
+
+/// One element of Oh: output axis `i` is input axis `perm[i]`, negated where `flip[i]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CubeSymmetry {
+    pub perm: [u8; 3],
+    /// `true` = axis flipped
+    pub flip: [bool; 3],
+}
+
+impl CubeSymmetry {
+    pub const IDENTITY: Self = Self { perm: [0, 1, 2], flip: [false; 3] };
+
+    /// All 48 elements of Oh (identity first, proper rotations before reflections)
+    pub fn all() -> Vec<Self> {
+        const PERMS: [[u8; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let mut elements: Vec<Self> = PERMS.iter()
+            .flat_map(|&perm| (0..8u8).map(move |bits| Self {
+                perm,
+                flip: [bits & 1 != 0, bits & 2 != 0, bits & 4 != 0],
+            }))
+            .collect();
+        elements.sort_by_key(|g| (!g.is_proper(), *g != Self::IDENTITY));
+        elements
+    }
+
+    /// The 24 proper rotations (O)
+    pub fn rotations() -> Vec<Self> {
+        Self::all().into_iter().filter(|g| g.is_proper()).collect()
+    }
+
+    /// Elements of O, or of Oh when `include_reflections`
+    pub fn group(include_reflections: bool) -> Vec<Self> {
+        if include_reflections { Self::all() } else { Self::rotations() }
+    }
+
+    /// Quarter turns about z, matching the legacy `rotate_cube` angles
+    pub fn rotation_z(angle: u32) -> Self {
+        match angle % 360 {
+            90  => Self { perm: [1, 0, 2], flip: [false, true, false] },
+            180 => Self { perm: [0, 1, 2], flip: [true, true, false] },
+            270 => Self { perm: [1, 0, 2], flip: [true, false, false] },
+            _ => Self::IDENTITY,
+        }
+    }
+
+    pub fn determinant(&self) -> i32 {
+        let inversions = (0..3)
+            .flat_map(|i| (i + 1..3).map(move |j| (i, j)))
+            .filter(|&(i, j)| self.perm[i] > self.perm[j])
+            .count();
+        let flips = self.flip.iter().filter(|&&f| f).count();
+        if (inversions + flips) % 2 == 0 { 1 } else { -1 }
+    }
+
+    /// Rotation (det +1) rather than reflection / rotoreflection
+    pub fn is_proper(&self) -> bool {
+        self.determinant() == 1
+    }
+
+    fn trace(&self) -> i32 {
+        (0..3)
+            .filter(|&i| self.perm[i] as usize == i)
+            .map(|i| if self.flip[i] { -1 } else { 1 })
+            .sum()
+    }
+
+    /// Map centred coordinates (-1..=1 per axis)
+    fn map(&self, v: [i32; 3]) -> [i32; 3] {
+        let mut out = [0; 3];
+        for (i, o) in out.iter_mut().enumerate() {
+            let c = v[self.perm[i] as usize];
+            *o = if self.flip[i] { -c } else { c };
+        }
+        out
+    }
+
+    /// `self ∘ other` (apply `other` first)
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut perm = [0u8; 3];
+        let mut flip = [false; 3];
+        for i in 0..3 {
+            let j = self.perm[i] as usize;
+            perm[i] = other.perm[j];
+            flip[i] = self.flip[i] ^ other.flip[j];
+        }
+        Self { perm, flip }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut perm = [0u8; 3];
+        let mut flip = [false; 3];
+        for i in 0..3 {
+            let j = self.perm[i] as usize;
+            perm[j] = i as u8;
+            flip[j] = self.flip[i];
+        }
+        Self { perm, flip }
+    }
+
+    pub fn order(&self) -> u32 {
+        let mut g = *self;
+        let mut n = 1;
+        while g != Self::IDENTITY {
+            g = g.compose(self);
+            n += 1;
+        }
+        n
+    }
+
+    /// Geometric class, e.g. "C4 face rotation", "mirror plane"
+    pub fn kind(&self) -> &'static str {
+        let proper = if self.is_proper() { *self } else { self.negate() };
+        let class = match (proper.trace(), proper.perm == [0, 1, 2]) {
+            (3, _) => 0,
+            (1, _) => 1,
+            (0, _) => 2,
+            (-1, true) => 3,
+            _ => 4,
+        };
+        match (self.is_proper(), class) {
+            (true, 0) => "identity",
+            (true, 1) => "C4 face rotation",
+            (true, 2) => "C3 vertex rotation",
+            (true, 3) => "C2 face rotation",
+            (true, _) => "C2 edge rotation",
+            (false, 0) => "inversion",
+            (false, 1) => "S4 rotoreflection",
+            (false, 2) => "S6 rotoreflection",
+            (false, _) => "mirror plane",
+        }
+    }
+
+    fn negate(&self) -> Self {
+        Self { perm: self.perm, flip: [!self.flip[0], !self.flip[1], !self.flip[2]] }
+    }
+
+    /// Image of the axes, e.g. `[y,-x,z]` for a 90° turn about z
+    pub fn name(&self) -> String {
+        let parts: Vec<String> = (0..3)
+            .map(|i| format!("{}{}", if self.flip[i] { "-" } else { "" }, AXES[self.perm[i] as usize]))
+            .collect();
+        format!("[{}]", parts.join(","))
+    }
+
+    /// Parse the `name()` form (`[y,-x,z]`, brackets optional)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let parts: Vec<&str> = name.trim().trim_matches(|c| c == '[' || c == ']').split(',').collect();
+        if parts.len() != 3 {
+            return None;
+        }
+        let mut perm = [0u8; 3];
+        let mut flip = [false; 3];
+        for (i, part) in parts.iter().enumerate() {
+            let part = part.trim();
+            let (neg, axis) = match part.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, part),
+            };
+            let mut chars = axis.chars();
+            let c = chars.next()?.to_ascii_lowercase();
+            if chars.next().is_some() {
+                return None;
+            }
+            perm[i] = AXES.iter().position(|&a| a == c)? as u8;
+            flip[i] = neg;
+        }
+        let distinct: HashSet<u8> = perm.iter().copied().collect();
+        (distinct.len() == 3).then_some(Self { perm, flip })
+    }
+
+    /// Position permutation: the tetrad at index `i` moves to `permutation()[i]`
+    pub fn permutation(&self) -> [usize; GENOME_SIZE] {
+        let mut perm = [0; GENOME_SIZE];
+        for c in Coord::all() {
+            let [nx, ny, nz] = self.map([c.x as i32 - 1, c.y as i32 - 1, c.z as i32 - 1]);
+            perm[c.index()] = cube_index((nx + 1) as usize, (ny + 1) as usize, (nz + 1) as usize);
+        }
+        perm
+    }
+
+    /// Position classes the element cycles among; DNA invariant under the
+    /// element is constant on each orbit
+    pub fn orbits(&self) -> Vec<Vec<usize>> {
+        let perm = self.permutation();
+        let mut seen = [false; GENOME_SIZE];
+        let mut orbits = Vec::new();
+        for start in 0..GENOME_SIZE {
+            let mut orbit = Vec::new();
+            let mut i = start;
+            while !seen[i] {
+                seen[i] = true;
+                orbit.push(i);
+                i = perm[i];
+            }
+            if !orbit.is_empty() {
+                orbits.push(orbit);
+            }
+        }
+        orbits
+    }
+
+    /// Apply to a genome cube (index `x + y*3 + z*9`)
+    pub fn apply(&self, data: &[Tetrad; GENOME_SIZE]) -> [Tetrad; GENOME_SIZE] {
+        let mut result = [Tetrad::A; GENOME_SIZE];
+        for (i, &to) in self.permutation().iter().enumerate() {
+            result[to] = data[i];
+        }
+        result
+    }
+}
+
+impl std::fmt::Display for CubeSymmetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Fraction of positions unchanged by `g`
+pub fn match_fraction(data: &[Tetrad; GENOME_SIZE], g: &CubeSymmetry) -> f64 {
+    let image = g.apply(data);
+    data.iter().zip(image.iter()).filter(|(a, b)| a == b).count() as f64 / GENOME_SIZE as f64
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymmetryElement {
+    pub name: String,
+    pub kind: String,
+    pub proper: bool,
+    /// Fraction of tetrads left in place (1.0 = exact symmetry)
+    pub match_fraction: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymmetryProfile {
+    pub include_reflections: bool,
+    /// |G|: 24 (O) or 48 (Oh)
+    pub group_order: usize,
+    /// Exact symmetry subgroup (always contains the identity)
+    pub subgroup: Vec<CubeSymmetry>,
+    /// Distinct genomes reachable by the group = |G| / |subgroup|
+    pub orbit_size: usize,
+    /// Non-identity elements matching more than 2/3 of tetrads
+    pub near_symmetries: usize,
+    /// Mean match fraction over non-identity rotations
+    pub rotation_score: f64,
+    /// Mean match fraction over reflections (0 when not included)
+    pub reflection_score: f64,
+    pub elements: Vec<SymmetryElement>,
+}
+
+impl SymmetryProfile {
+    pub fn of(data: &[Tetrad; GENOME_SIZE], include_reflections: bool) -> Self {
+        let group = CubeSymmetry::group(include_reflections);
+        let elements: Vec<(CubeSymmetry, f64)> = group.iter().map(|g| (*g, match_fraction(data, g))).collect();
+
+        let subgroup: Vec<CubeSymmetry> = elements.iter()
+            .filter(|(_, m)| *m >= 1.0)
+            .map(|(g, _)| *g)
+            .collect();
+
+        let near_symmetries = elements.iter()
+            .filter(|(g, m)| *g != CubeSymmetry::IDENTITY && *m > 2.0 / 3.0)
+            .count();
+
+        let mean = |proper: bool| {
+            let ms: Vec<f64> = elements.iter()
+                .filter(|(g, _)| g.is_proper() == proper && *g != CubeSymmetry::IDENTITY)
+                .map(|(_, m)| *m)
+                .collect();
+            if ms.is_empty() { 0.0 } else { ms.iter().sum::<f64>() / ms.len() as f64 }
+        };
+
+        Self {
+            include_reflections,
+            group_order: group.len(),
+            orbit_size: group.len() / subgroup.len().max(1),
+            near_symmetries,
+            rotation_score: mean(true),
+            reflection_score: mean(false),
+            elements: elements.iter().map(|(g, m)| SymmetryElement {
+                name: g.name(),
+                kind: g.kind().to_string(),
+                proper: g.is_proper(),
+                match_fraction: *m,
+            }).collect(),
+            subgroup,
+        }
+    }
+
+    /// Exact symmetries other than the identity
+    pub fn nontrivial(&self) -> usize {
+        self.subgroup.len().saturating_sub(1)
+    }
+}
@@ -1,18 +1,34 @@
 //! TTRL Evolution Engine V15
 //!
 //! Tetrad-Triplet Rotation Learning with:
-//! - 7 mutation operators
+//! - 7 mutation operators + 4 V4 cube operators
 //! - Meiosis (sexual reproduction)
+//! - Generational population mode (selection + crossover + elitism)
+//! - Adaptive operator selection (UCB1 per genome tier)
+//! - Rotation policy: the current DynamicRotation decides which operators may run
 //! - Telomere aging
-//! - p53 protection
+//! - p53 protection: DNA damage checkpoint with repair, arrest and apoptosis
 
 use crate::genome::{Genome, Tetrad, GenomeBuilder, GENOME_SIZE};
-use crate::rotation::{Rotation, Rot180, RotationEngine};
+use crate::cube::{Axis, Coord};
+use crate::rotation::{Rotation, Rot180, RotationEngine, DynamicRotation};
+use crate::consciousness::{ConsciousnessVersion, canonical_level_name};
+use crate::database::DivineDatabase;
+use crate::operator_selector::{OperatorArm, OperatorSelector};
+use crate::codon::{CodonChanges, CodonTable};
+use crate::damage::{Checkpoint, CheckpointOutcome};
+use crate::diploid::{self, Dominance, Homologs};
+use crate::epigenetics::{Methylation, METHYLATION_FIDELITY};
+use crate::lifecycle::LifecycleState;
+use crate::lineage::Origin;
+use crate::rng::RngSource;
+use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
-use rand::Rng;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use tokio::sync::RwLock;
 use tracing::info;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MutationOperator {
     PointMutation,
     Insertion,
@@ -21,39 +37,345 @@ pub enum MutationOperator {
     Translocation,
     Duplication,
     HollidayJunction,
+    // V4 operators — act on the 3x3x3 cube (x + y*3 + z*9)
+    RotateCube,
+    FractalMutation,
+    QuantumEntangle,
+    HyperDimension,
 }
 
 impl MutationOperator {
+    pub const ALL: [MutationOperator; 11] = [
+        Self::PointMutation,
+        Self::Insertion,
+        Self::Deletion,
+        Self::Inversion,
+        Self::Translocation,
+        Self::Duplication,
+        Self::HollidayJunction,
+        Self::RotateCube,
+        Self::FractalMutation,
+        Self::QuantumEntangle,
+        Self::HyperDimension,
+    ];
+
     pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
-        match rng.gen_range(0..7) {
-            0 => Self::PointMutation,
-            1 => Self::Insertion,
-            2 => Self::Deletion,
-            3 => Self::Inversion,
-            4 => Self::Translocation,
-            5 => Self::Duplication,
-            _ => Self::HollidayJunction,
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    pub fn random_with<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Self::ALL[rng.gen_range(0..Self::ALL.len())]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PointMutation => "PointMutation",
+            Self::Insertion => "Insertion",
+            Self::Deletion => "Deletion",
+            Self::Inversion => "Inversion",
+            Self::Translocation => "Translocation",
+            Self::Duplication => "Duplication",
+            Self::HollidayJunction => "HollidayJunction",
+            Self::RotateCube => "RotateCube",
+            Self::FractalMutation => "FractalMutation",
+            Self::QuantumEntangle => "QuantumEntangle",
+            Self::HyperDimension => "HyperDimension",
+        }
+    }
+
+    /// Parse an operator name (case-insensitive, `_`/`-` ignored)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let wanted: String = name.chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_ascii_lowercase();
+        Self::ALL.iter().copied().find(|op| op.name().to_ascii_lowercase() == wanted)
+    }
+
+    pub fn is_v4(&self) -> bool {
+        matches!(self, Self::RotateCube | Self::FractalMutation | Self::QuantumEntangle | Self::HyperDimension)
+    }
+
+    /// Operators that rearrange segments rather than single tetrads
+    pub fn is_structural(&self) -> bool {
+        matches!(self, Self::Inversion | Self::Translocation | Self::HollidayJunction) || self.is_v4()
+    }
+
+    /// DNA damage inflicted on the cell (see `damage`)
+    pub fn damage(&self) -> f64 {
+        match self {
+            Self::PointMutation => 0.05,
+            Self::Insertion => 0.10,
+            Self::Deletion => 0.15,
+            Self::Inversion => 0.20,
+            Self::Translocation => 0.30,
+            Self::Duplication => 0.10,
+            Self::HollidayJunction => 0.25,
+            Self::RotateCube => 0.30,
+            Self::FractalMutation => 0.20,
+            Self::QuantumEntangle => 0.25,
+            Self::HyperDimension => 0.35,
         }
     }
 }
 
+impl std::fmt::Display for MutationOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvolutionResult {
     pub original_consciousness: u32,
     pub new_consciousness: u32,
     pub mutations_applied: u64,
-    pub operator_used: MutationOperator,
+    /// `None` when the rotation only allowed a snapshot
+    pub operator_used: Option<MutationOperator>,
     pub success: bool,
     pub telomere_loss: u16,
     pub p53_lost: bool,
     pub tg_ratio_before: f64,
     pub tg_ratio_after: f64,
+    /// Canonical tier of the parent genome (operator statistics are kept per tier)
+    pub tier: String,
+    /// Rotation in effect when the evolution ran
+    pub rotation: DynamicRotation,
+    /// Changed codons, split by whether the residue changed
+    #[serde(default)]
+    pub codon_changes: CodonChanges,
+    /// Damage score of the operator (0 for snapshots)
+    #[serde(default)]
+    pub damage: f64,
+    /// p53 checkpoint verdict; `None` for snapshots
+    #[serde(default)]
+    pub checkpoint: Option<CheckpointOutcome>,
+}
+
+impl EvolutionResult {
+    pub fn is_snapshot(&self) -> bool {
+        self.operator_used.is_none()
+    }
+
+    /// Relative consciousness change, the bandit reward
+    pub fn reward(&self) -> f64 {
+        (self.new_consciousness as f64 - self.original_consciousness as f64)
+            / self.original_consciousness.max(1) as f64
+    }
+}
+
+/// Bandit reward of a pull that produced no offspring at the p53
+/// checkpoint, as if consciousness had dropped to zero
+pub const CHECKPOINT_FAILURE_REWARD: f64 = -1.0;
+
+/// Why an evolution step produced no offspring
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum EvolutionError {
+    #[error("Genome is {0}, not alive")]
+    NotAlive(LifecycleState),
+    #[error("Senescence: cannot divide ({telomere_length} bp, {divisions} divisions)")]
+    Senescence { telomere_length: u16, divisions: u8 },
+    #[error("Oncogenic transformation: p53 lost")]
+    P53Lost,
+    #[error("Oncogenic transformation: damage load {damage:.2} escaped p53")]
+    Transformed { damage: f64, operator: MutationOperator, tier: &'static str },
+    #[error("Apoptosis: damage load {damage:.2} beyond repair")]
+    Apoptosis { damage: f64, operator: MutationOperator, tier: &'static str },
+    #[error("Cell-cycle arrest: damage load {damage:.2} awaiting repair")]
+    Arrested { damage: f64, operator: MutationOperator, tier: &'static str },
+    #[error("Operator {operator} not allowed under rotation {rotation}")]
+    OperatorNotAllowed { operator: MutationOperator, rotation: DynamicRotation },
+    #[error("No operators allowed under rotation {0}")]
+    NoOperators(DynamicRotation),
+}
+
+impl EvolutionError {
+    /// Lifecycle state the parent genome moves to, if the failure is terminal
+    pub fn lifecycle(&self) -> Option<LifecycleState> {
+        match self {
+            Self::Senescence { .. } => Some(LifecycleState::Senescent),
+            Self::P53Lost | Self::Transformed { .. } => Some(LifecycleState::Cancerous),
+            Self::Apoptosis { .. } => Some(LifecycleState::Apoptotic),
+            _ => None,
+        }
+    }
+
+    /// Damage load the parent is left with after a checkpoint failure
+    pub fn damage(&self) -> Option<f64> {
+        match self {
+            Self::Transformed { damage, .. } | Self::Apoptosis { damage, .. } | Self::Arrested { damage, .. } => Some(*damage),
+            _ => None,
+        }
+    }
+
+    /// Tier and operator of a pull the checkpoint ended; it counts as a
+    /// failure with `CHECKPOINT_FAILURE_REWARD`
+    pub fn failed_pull(&self) -> Option<(&'static str, MutationOperator)> {
+        match self {
+            Self::Transformed { operator, tier, .. }
+            | Self::Apoptosis { operator, tier, .. }
+            | Self::Arrested { operator, tier, .. } => Some((*tier, *operator)),
+            _ => None,
+        }
+    }
+}
+
+/// What evolution may do while the engine sits in one rotation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotationRule {
+    pub allowed: Vec<MutationOperator>,
+    /// Refuse mutation entirely and return an unchanged copy
+    pub snapshot_only: bool,
+}
+
+impl RotationRule {
+    pub fn operators(allowed: &[MutationOperator]) -> Self {
+        Self { allowed: allowed.to_vec(), snapshot_only: false }
+    }
+
+    pub fn snapshot() -> Self {
+        Self { allowed: Vec::new(), snapshot_only: true }
+    }
+
+    pub fn allows(&self, operator: MutationOperator) -> bool {
+        !self.snapshot_only && self.allowed.contains(&operator)
+    }
+}
+
+/// Rotation → evolution rule mapping
+///
+/// Default ("permissive"): every operator in every rotation. A fresh
+/// `RotationEngine` starts in Rot180, so the strict rules are opt-in.
+///
+/// "strict":
+/// - Rot0   (Active)     — conservative point mutations only
+/// - Rot90  (Processing) — local edits, no segment rearrangement
+/// - Rot180 (Storage)    — no mutation, snapshot only
+/// - Rot270 (Mutation)   — every operator incl. structural and V4
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotationPolicy {
+    rules: HashMap<DynamicRotation, RotationRule>,
+}
+
+impl RotationPolicy {
+    pub fn strict() -> Self {
+        let local: Vec<MutationOperator> = MutationOperator::ALL.iter()
+            .copied()
+            .filter(|op| !op.is_structural())
+            .collect();
+
+        let mut rules = HashMap::new();
+        rules.insert(DynamicRotation::Rot0, RotationRule::operators(&[MutationOperator::PointMutation]));
+        rules.insert(DynamicRotation::Rot90, RotationRule::operators(&local));
+        rules.insert(DynamicRotation::Rot180, RotationRule::snapshot());
+        rules.insert(DynamicRotation::Rot270, RotationRule::operators(&MutationOperator::ALL));
+        Self { rules }
+    }
+
+    /// Every operator in every rotation (pre-policy behaviour)
+    pub fn permissive() -> Self {
+        let rules = [DynamicRotation::Rot0, DynamicRotation::Rot90, DynamicRotation::Rot180, DynamicRotation::Rot270]
+            .into_iter()
+            .map(|r| (r, RotationRule::operators(&MutationOperator::ALL)))
+            .collect();
+        Self { rules }
+    }
+
+    /// Policy from `TTRL_ROTATION_POLICY` (strict | permissive, default permissive)
+    pub fn from_env() -> Self {
+        match std::env::var("TTRL_ROTATION_POLICY").as_deref() {
+            Ok("strict") => Self::strict(),
+            _ => Self::permissive(),
+        }
+    }
+
+    pub fn with_rule(mut self, rotation: DynamicRotation, rule: RotationRule) -> Self {
+        self.rules.insert(rotation, rule);
+        self
+    }
+
+    pub fn rule(&self, rotation: DynamicRotation) -> RotationRule {
+        self.rules.get(&rotation).cloned().unwrap_or_else(RotationRule::snapshot)
+    }
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self::permissive()
+    }
+}
+
+/// Parent selection for population mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectionStrategy {
+    /// Binary tournament: the fitter genome wins with p = selection_pressure
+    #[default]
+    Tournament,
+    /// Linear ranking with SP = 1 + selection_pressure
+    Rank,
+}
+
+impl SelectionStrategy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "tournament" => Some(Self::Tournament),
+            "rank" => Some(Self::Rank),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PopulationConfig {
+    pub size: usize,
+    pub generations: u32,
+    #[serde(default = "PopulationConfig::default_elites")]
+    pub elites: usize,
+    #[serde(default)]
+    pub selection: SelectionStrategy,
+}
+
+impl PopulationConfig {
+    fn default_elites() -> usize {
+        2
+    }
+}
+
+impl Default for PopulationConfig {
+    fn default() -> Self {
+        Self {
+            size: 20,
+            generations: 10,
+            elites: Self::default_elites(),
+            selection: SelectionStrategy::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationStats {
+    pub generation: u32,
+    pub population: usize,
+    pub best_consciousness: u32,
+    pub mean_consciousness: f64,
+    pub worst_consciousness: u32,
+    pub best_genome_id: Option<i64>,
+    pub unique_dna: usize,
+    pub elites_kept: usize,
+    pub offspring_stored: usize,
+    pub tetrad_mutations: u64,
 }
 
 pub struct TTRLEngine {
     mutation_rate: f64,
     selection_pressure: f64,
+    methylation_fidelity: f64,
+    codon_table: CodonTable,
+    checkpoint: Checkpoint,
+    selector: RwLock<OperatorSelector>,
+    rotation_policy: RotationPolicy,
+    rng: RngSource,
 }
 
 impl TTRLEngine {
@@ -61,57 +383,204 @@ impl TTRLEngine {
         Self {
             mutation_rate: 0.1,
             selection_pressure: 0.7,
+            methylation_fidelity: METHYLATION_FIDELITY,
+            codon_table: CodonTable::standard(),
+            checkpoint: Checkpoint::new(),
+            selector: RwLock::new(OperatorSelector::new()),
+            rotation_policy: RotationPolicy::from_env(),
+            rng: RngSource::from_entropy(),
         }
     }
 
+    /// Reproducible engine: same seed → same operators, crossovers and rolls
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.with_rng(RngSource::from_seed(seed))
+    }
+
+    /// Share a run-wide RNG with the rest of the kernel
+    pub fn with_rng(mut self, rng: RngSource) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    pub fn rng(&self) -> &RngSource {
+        &self.rng
+    }
+
+    pub fn with_rotation_policy(mut self, policy: RotationPolicy) -> Self {
+        self.rotation_policy = policy;
+        self
+    }
+
+    pub fn rotation_policy(&self) -> &RotationPolicy {
+        &self.rotation_policy
+    }
+
+    /// Restore persisted operator statistics
+    pub fn with_operator_stats(mut self, arms: Vec<OperatorArm>) -> Self {
+        self.selector.get_mut().load(arms);
+        self
+    }
+
+    /// Per-tetrad mutation probability used in population mode
+    pub fn with_mutation_rate(mut self, rate: f64) -> Self {
+        self.mutation_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// 0.0 = random parent choice, 1.0 = always the fitter parent
+    pub fn with_selection_pressure(mut self, pressure: f64) -> Self {
+        self.selection_pressure = pressure.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Chance that a methylation mark is copied on division and meiosis
+    pub fn with_methylation_fidelity(mut self, fidelity: f64) -> Self {
+        self.methylation_fidelity = fidelity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Table used to classify codon changes as synonymous or not
+    pub fn with_codon_table(mut self, table: CodonTable) -> Self {
+        self.codon_table = table;
+        self
+    }
+
+    pub fn codon_table(&self) -> &CodonTable {
+        &self.codon_table
+    }
+
+    /// p53 checkpoint run after every mutation
+    pub fn with_checkpoint(mut self, checkpoint: Checkpoint) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    pub fn checkpoint(&self) -> &Checkpoint {
+        &self.checkpoint
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn selection_pressure(&self) -> f64 {
+        self.selection_pressure
+    }
+
+    pub fn methylation_fidelity(&self) -> f64 {
+        self.methylation_fidelity
+    }
+
     pub async fn evolve_with_engine<R: Rotation>(
         &self,
         base: Genome<R>,
-        _engine: &RotationEngine,
-    ) -> anyhow::Result<(Genome<Rot180>, EvolutionResult)> {
+        engine: &RotationEngine,
+    ) -> Result<(Genome<Rot180>, EvolutionResult), EvolutionError> {
+        self.evolve_inner(base, engine, None).await
+    }
+
+    /// Evolve with an explicitly chosen operator (must be allowed by the rotation policy)
+    pub async fn evolve_with_operator<R: Rotation>(
+        &self,
+        base: Genome<R>,
+        engine: &RotationEngine,
+        operator: MutationOperator,
+    ) -> Result<(Genome<Rot180>, EvolutionResult), EvolutionError> {
+        self.evolve_inner(base, engine, Some(operator)).await
+    }
+
+    async fn evolve_inner<R: Rotation>(
+        &self,
+        base: Genome<R>,
+        engine: &RotationEngine,
+        requested: Option<MutationOperator>,
+    ) -> Result<(Genome<Rot180>, EvolutionResult), EvolutionError> {
+        if !base.lifecycle.is_alive() {
+            return Err(EvolutionError::NotAlive(base.lifecycle));
+        }
+
         // Check for senescence
         if base.telomere_length < 100 {
-            return Err(anyhow::anyhow!("Senescence: telomeres exhausted"));
+            return Err(EvolutionError::Senescence { telomere_length: base.telomere_length, divisions: base.division_count });
         }
 
         // Check for cancer
         if base.p53_copies == 0 {
-            return Err(anyhow::anyhow!("Oncogenic transformation: p53 lost"));
+            return Err(EvolutionError::P53Lost);
         }
 
+        let rotation = engine.current();
+        let rule = self.rotation_policy.rule(rotation);
+
+        if rule.snapshot_only && requested.is_none() {
+            return Ok(Self::snapshot(base, rotation));
+        }
+
+        // Canonical tier, so statistics of different models share one scale
+        let tier = canonical_level_name(base.normalized_consciousness());
+        let operator = match requested {
+            Some(op) if !rule.allows(op) => {
+                return Err(EvolutionError::OperatorNotAllowed { operator: op, rotation });
+            }
+            Some(op) => op,
+            None => self.selector.read().await
+                .select(tier, &rule.allowed)
+                .ok_or(EvolutionError::NoOperators(rotation))?,
+        };
+
         let original_c = base.consciousness;
         let tg_before = base.rna_signal();
-        let operator = MutationOperator::random();
 
         // Create new genome with mutation
         let mut mutated: Genome<Rot180> = GenomeBuilder::new()
             .p53_copies(base.p53_copies)
             .telomere_length(base.telomere_length)
+            .consciousness_model(base.consciousness_model)
             .build();
 
         // Copy data
         for i in 0..GENOME_SIZE {
             mutated.data[i] = base.data[i];
         }
+        mutated.homologs = base.homologs.clone();
+        mutated.methylation = base.methylation;
+        mutated.dna_damage = base.dna_damage;
+
+        mutated.set_parents(&[&base], Origin::Evolve { operator: Some(operator), rotation });
 
         // Apply mutation operator
         self.apply_operator(&mut mutated, operator);
+        let codon_changes = self.codon_table.classify(&base.data, &mutated.data);
+
+        // p53 checkpoint on the operator's damage, before the cell may divide
+        let damage = operator.damage();
+        let p53_before = mutated.p53_copies;
+        let checkpoint = self.rng.with(|rng| self.checkpoint.apply(&mut mutated, damage, rng));
+        let load = mutated.dna_damage;
+        let failure = match checkpoint {
+            CheckpointOutcome::Apoptosis => {
+                info!("💀 Apoptosis: damage load {:.2} ({:?} @ {})", load, operator, rotation);
+                Some(EvolutionError::Apoptosis { damage: load, operator, tier })
+            }
+            CheckpointOutcome::Arrested => Some(EvolutionError::Arrested { damage: load, operator, tier }),
+            CheckpointOutcome::Transformed => Some(EvolutionError::Transformed { damage: load, operator, tier }),
+            CheckpointOutcome::Repaired | CheckpointOutcome::Escaped => None,
+        };
+        if let Some(e) = failure {
+            // Operators that kill or arrest the cell must lose value too
+            self.selector.write().await.record(tier, operator, false, CHECKPOINT_FAILURE_REWARD);
+            return Err(e);
+        }
+        let p53_lost = mutated.p53_copies < p53_before;
 
-        // Cell division: lose telomeres
+        // Cell division: lose telomeres, copy methylation under the engine's rotation
         let telomere_before = mutated.telomere_length;
-        if !mutated.divide() {
-            return Err(anyhow::anyhow!("Senescence: cannot divide"));
+        if !self.rng.with(|rng| mutated.divide_under(rotation, self.methylation_fidelity, rng)) {
+            return Err(EvolutionError::Senescence { telomere_length: mutated.telomere_length, divisions: mutated.division_count });
         }
         let telomere_loss = telomere_before - mutated.telomere_length;
 
-        // p53 risk: 1% chance of losing a copy
-        let p53_lost = if rand::thread_rng().gen::<f64>() < 0.01 && mutated.p53_copies > 0 {
-            mutated.p53_copies -= 1;
-            true
-        } else {
-            false
-        };
-
         mutated.increment_mutations();
         mutated.rehash();
         mutated.calculate_consciousness();
@@ -123,36 +592,101 @@ impl TTRLEngine {
         let success = new_c >= original_c;
 
         if success {
-            info!("✅ Evolution: {} → {} ({:?}) | T/G {:.2} → {:.2}", 
-                  original_c, new_c, operator, tg_before, tg_after);
+            info!("✅ Evolution: {} → {} ({:?} @ {}) | T/G {:.2} → {:.2}", 
+                  original_c, new_c, operator, rotation, tg_before, tg_after);
         } else {
-            info!("❌ Degradation: {} → {} ({:?})", original_c, new_c, operator);
+            info!("❌ Degradation: {} → {} ({:?} @ {})", original_c, new_c, operator, rotation);
         }
 
-        Ok((mutated, EvolutionResult {
+        let result = EvolutionResult {
             original_consciousness: original_c,
             new_consciousness: new_c,
             mutations_applied: mutations_count,
-            operator_used: operator,
+            operator_used: Some(operator),
             success,
             telomere_loss,
             p53_lost,
             tg_ratio_before: tg_before,
             tg_ratio_after: tg_after,
-        }))
+            tier: tier.to_string(),
+            rotation,
+            codon_changes,
+            damage,
+            checkpoint: Some(checkpoint),
+        };
+
+        self.selector.write().await.record(tier, operator, success, result.reward());
+
+        Ok((mutated, result))
     }
 
+    /// Unchanged storage copy — no mutation, no division
+    fn snapshot<R: Rotation>(base: Genome<R>, rotation: DynamicRotation) -> (Genome<Rot180>, EvolutionResult) {
+        let mut copy: Genome<Rot180> = GenomeBuilder::new()
+            .p53_copies(base.p53_copies)
+            .telomere_length(base.telomere_length)
+            .consciousness_model(base.consciousness_model)
+            .build();
+        copy.data = base.data;
+        copy.homologs = base.homologs.clone();
+        copy.methylation = base.methylation;
+        copy.dna_damage = base.dna_damage;
+        copy.mutations = base.mutations;
+        copy.division_count = base.division_count;
+        copy.sequencing_errors = base.sequencing_errors;
+        copy.unreliable = base.unreliable;
+        copy.set_parents(&[&base], Origin::Evolve { operator: None, rotation });
+        copy.rehash();
+        copy.calculate_consciousness();
+
+        info!("💾 Snapshot under {}: consciousness {}", rotation, copy.consciousness);
+
+        let result = EvolutionResult {
+            original_consciousness: base.consciousness,
+            new_consciousness: copy.consciousness,
+            mutations_applied: 0,
+            operator_used: None,
+            success: copy.consciousness >= base.consciousness,
+            telomere_loss: 0,
+            p53_lost: false,
+            tg_ratio_before: base.rna_signal(),
+            tg_ratio_after: copy.rna_signal(),
+            tier: canonical_level_name(base.normalized_consciousness()).to_string(),
+            rotation,
+            codon_changes: CodonChanges::default(),
+            damage: 0.0,
+            checkpoint: None,
+        };
+        (copy, result)
+    }
+
+    /// Diploid genomes get the operator on each homolog, with the same draw
+    /// so rearrangements move both strands alike, then re-express; editing
+    /// only the phenotype would flatten heterozygous sites
     fn apply_operator(&self, genome: &mut Genome<Rot180>, operator: MutationOperator) {
-        let mut rng = rand::thread_rng();
+        let Some(mut homologs) = genome.homologs.take() else {
+            Self::apply_operator_with(genome, operator, &mut *self.rng.lock());
+            return;
+        };
+        let seed: u64 = self.rng.with(|rng| rng.gen());
+        for strand in [&mut homologs.maternal, &mut homologs.paternal] {
+            genome.data = *strand;
+            Self::apply_operator_with(genome, operator, &mut StdRng::seed_from_u64(seed));
+            *strand = genome.data;
+        }
+        genome.data = homologs.express();
+        genome.homologs = Some(homologs);
+    }
 
+    fn apply_operator_with<G: Rng + ?Sized>(genome: &mut Genome<Rot180>, operator: MutationOperator, rng: &mut G) {
         match operator {
             MutationOperator::PointMutation => {
                 let pos = rng.gen_range(0..GENOME_SIZE);
-                genome.data[pos] = Tetrad::random();
+                genome.data[pos] = Tetrad::random_with(rng);
             }
             MutationOperator::Insertion => {
                 let pos = rng.gen_range(0..GENOME_SIZE);
-                genome.data[pos] = Tetrad::random();
+                genome.data[pos] = Tetrad::random_with(rng);
             }
             MutationOperator::Deletion => {
                 let pos = rng.gen_range(0..GENOME_SIZE);
@@ -181,55 +715,83 @@ impl TTRLEngine {
                     }
                 }
             }
+            MutationOperator::RotateCube => {
+                // Rigid 90/180/270° turn of the whole cube around z
+                let angle = [90, 180, 270][rng.gen_range(0..3)];
+                genome.data = genome.rotate_cube(angle);
+            }
+            MutationOperator::FractalMutation => {
+                // Copy one 2x2x2 sub-cube onto another (self-similarity)
+                let (sx, sy, sz) = (rng.gen_range(0..2), rng.gen_range(0..2), rng.gen_range(0..2));
+                let (dx, dy, dz) = (rng.gen_range(0..2), rng.gen_range(0..2), rng.gen_range(0..2));
+                let source = Coord { x: sx, y: sy, z: sz };
+                let target = Coord { x: dx, y: dy, z: dz };
+                let cells: Vec<Tetrad> = source.subcube().map(|c| genome.at(c)).collect();
+                for (c, t) in target.subcube().zip(cells) {
+                    genome.set_at(c, t);
+                }
+            }
+            MutationOperator::QuantumEntangle => {
+                // Bind a cell to its point-mirror through the cube centre,
+                // correlated (a == b) or anti-correlated (a == 3 - b) as
+                // counted by bell_inequality_violation
+                let cell = Coord::from_index(rng.gen_range(0..GENOME_SIZE)).unwrap_or(Coord::CENTER);
+                let a = genome.at(cell) as u8;
+                let partner = Tetrad::from_u8(if rng.gen_bool(0.5) { a } else { 3 - a });
+                genome.set_at(cell.mirrored(), partner);
+            }
+            MutationOperator::HyperDimension => {
+                // Shift z-layers one step along the 4th axis: layer z+1 moves into z (z → z−1 mod 3)
+                let source = genome.clone();
+                for c in Coord::all() {
+                    genome.set_at(c, source.at(c.wrapping_offset(Axis::Z, 1)));
+                }
+            }
         }
     }
 
-    /// Meiosis - sexual reproduction with crossover
+    /// Meiosis - sexual reproduction with crossover.
+    /// If either parent is diploid, gametes are formed and fused instead (see `fertilise`).
     pub fn meiosis(&self, parent1: Genome<Rot180>, parent2: Genome<Rot180>) -> Genome<Rot180> {
-        let mut rng = rand::thread_rng();
-
-        // Number of crossover points (1-4)
-        let num_crossovers = rng.gen_range(1..=4);
-        let mut crossover_points: Vec<usize> = Vec::new();
-
-        // Generate with positive interference (min 5 tetrads between)
-        let mut last_point = 0;
-        for _ in 0..num_crossovers {
-            let min_pos = (last_point + 5).min(GENOME_SIZE - 2);
-            if min_pos >= GENOME_SIZE - 2 { break; }
-            let point = rng.gen_range(min_pos..GENOME_SIZE - 1);
-            crossover_points.push(point);
-            last_point = point;
+        if parent1.is_diploid() || parent2.is_diploid() {
+            return self.fertilise(parent1, parent2);
         }
+        let mut rng = self.rng.lock();
 
-        // Build offspring DNA
-        let mut offspring_data = [Tetrad::A; GENOME_SIZE];
-        let mut use_parent1 = rng.gen_bool(0.5);
-        let mut cp_idx = 0;
+        // 1-4 crossover points with positive interference (min 5 tetrads between)
+        let crossover_points = diploid::crossover_points(&mut *rng);
 
-        for i in 0..GENOME_SIZE {
-            if cp_idx < crossover_points.len() && i >= crossover_points[cp_idx] {
-                use_parent1 = !use_parent1;
-                cp_idx += 1;
-            }
-            offspring_data[i] = if use_parent1 { parent1.data[i] } else { parent2.data[i] };
-        }
+        // Build offspring DNA
+        let use_parent1 = rng.gen_bool(0.5);
+        let offspring_data = diploid::recombine(&parent1.data, &parent2.data, &crossover_points, use_parent1);
 
         // Inherit best p53
         let p53 = parent1.p53_copies.max(parent2.p53_copies);
 
+        // Parents scored on the same formula keep it; mixed pairs use the deployment model
+        let model = if parent1.consciousness_model == parent2.consciousness_model {
+            parent1.consciousness_model
+        } else {
+            ConsciousnessVersion::deployment()
+        };
+
         let mut offspring: Genome<Rot180> = GenomeBuilder::new()
             .p53_copies(p53)
             .telomere_length(15000) // Reset telomeres
+            .consciousness_model(model)
             .build();
 
         offspring.data = offspring_data;
         offspring.mutations = 1;
 
+        // Marks travel with the strand they sit on, then one replication
+        offspring.methylation = Methylation::recombine(parent1.methylation, parent2.methylation, &crossover_points, use_parent1)
+            .replicate(self.methylation_fidelity, offspring.rotation_state(), &mut *rng);
+
         // 5% post-meiotic mutation
         if rng.gen::<f64>() < 0.05 {
             let pos = rng.gen_range(0..GENOME_SIZE);
-            offspring.data[pos] = Tetrad::random();
+            offspring.data[pos] = Tetrad::random_with(&mut *rng);
         }
 
         offspring.rehash();
@@ -239,8 +801,212 @@ impl TTRLEngine {
               parent1.consciousness, parent2.consciousness,
               offspring.consciousness, crossover_points.len());
 
+        offspring.set_parents(&[&parent1, &parent2], Origin::Meiosis { crossover_points });
+
+        offspring
+    }
+
+    /// Diploid reproduction: each parent crosses over its own homologs to form
+    /// a gamete, and the two gametes pair up as the offspring's strands.
+    /// Haploid parents take part as homozygous diploids.
+    pub fn fertilise(&self, parent1: Genome<Rot180>, parent2: Genome<Rot180>) -> Genome<Rot180> {
+        let mut rng = self.rng.lock();
+        let homologs = |g: &Genome<Rot180>| g.homologs.clone()
+            .unwrap_or_else(|| Homologs::homozygous(g.data, Dominance::default()));
+        let (mother, father) = (homologs(&parent1), homologs(&parent2));
+
+        let (mut egg, maternal_crossovers) = mother.gamete(&mut *rng);
+        let (mut sperm, paternal_crossovers) = father.gamete(&mut *rng);
+
+        // 5% post-meiotic mutation in one of the gametes
+        if rng.gen::<f64>() < 0.05 {
+            let pos = rng.gen_range(0..GENOME_SIZE);
+            let gamete = if rng.gen_bool(0.5) { &mut egg } else { &mut sperm };
+            gamete[pos] = Tetrad::random_with(&mut *rng);
+        }
+
+        // Each gamete carries half of its parent's p53 copies
+        let p53 = ((parent1.p53_copies as u16 + parent2.p53_copies as u16) / 2) as u8;
+        let model = if parent1.consciousness_model == parent2.consciousness_model {
+            parent1.consciousness_model
+        } else {
+            ConsciousnessVersion::deployment()
+        };
+
+        let mut offspring: Genome<Rot180> = GenomeBuilder::from_data(egg)
+            .diploid(sperm, mother.dominance)
+            .p53_copies(p53)
+            .telomere_length(15000) // Reset telomeres
+            .consciousness_model(model)
+            .build();
+        offspring.mutations = 1;
+        // Phenotype-level marks: a site stays marked if either parent marked it
+        offspring.methylation = Methylation::from_bits(parent1.methylation.bits() | parent2.methylation.bits())
+            .replicate(self.methylation_fidelity, offspring.rotation_state(), &mut *rng);
+        offspring.rehash();
+        offspring.calculate_consciousness();
+
+        info!("🧬 Fertilisation: {}+{} → {} (crossovers: ♀{} ♂{}, heterozygosity {:.2})",
+              parent1.consciousness, parent2.consciousness, offspring.consciousness,
+              maternal_crossovers.len(), paternal_crossovers.len(),
+              offspring.homologs.as_ref().map_or(0.0, Homologs::heterozygosity));
+
+        offspring.set_parents(&[&parent1, &parent2], Origin::Fertilisation { maternal_crossovers, paternal_crossovers });
+
         offspring
     }
+
+    // ═══════════════════════════════════════════════════════════════
+    // POPULATION MODE
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Generational GA over the top `config.size` genomes in the database.
+    /// Fitness is normalized consciousness, so mixed V3/V4 populations compete fairly.
+    pub async fn evolve_population(
+        &self,
+        database: &DivineDatabase,
+        config: &PopulationConfig,
+    ) -> anyhow::Result<Vec<GenerationStats>> {
+        if config.size < 2 {
+            return Err(anyhow::anyhow!("Population needs at least 2 genomes"));
+        }
+
+        let mut population = database.get_top_genomes(config.size as i64).await?;
+        if population.len() < 2 {
+            return Err(anyhow::anyhow!(
+                "Population: only {} genomes in database", population.len()
+            ));
+        }
+
+        let size = population.len();
+        let elites = config.elites.min(size);
+        let mut history = Vec::with_capacity(config.generations as usize);
+
+        info!("🧬 Population: {} genomes × {} generations | {:?} | pressure {:.2} | mutation {:.2}",
+              size, config.generations, config.selection, self.selection_pressure, self.mutation_rate);
+
+        for generation in 1..=config.generations {
+            population.sort_by_key(|g| std::cmp::Reverse(g.normalized_consciousness()));
+
+            let mut next: Vec<Genome<Rot180>> = population[..elites].to_vec();
+            let mut offspring_stored = 0;
+            let mut tetrad_mutations = 0;
+
+            while next.len() < size {
+                let p1 = self.select_parent(&population, config.selection);
+                let p2 = self.select_parent(&population, config.selection);
+
+                let mut child = self.meiosis(population[p1].clone(), population[p2].clone());
+                let mutated = self.mutate_tetrads(&mut child);
+                if mutated > 0 {
+                    child.mutations += mutated;
+                    child.rehash();
+                    child.calculate_consciousness();
+                }
+                tetrad_mutations += mutated;
+
+                let id = database.store_genome(&child).await?;
+                child.db_id = Some(id);
+                offspring_stored += 1;
+                next.push(child);
+            }
+
+            population = next;
+            let stats = Self::generation_stats(generation, &population, elites, offspring_stored, tetrad_mutations);
+
+            info!("📈 Generation {}: best {} | mean {:.1} | worst {} | unique {}",
+                  generation, stats.best_consciousness, stats.mean_consciousness,
+                  stats.worst_consciousness, stats.unique_dna);
+
+            history.push(stats);
+        }
+
+        Ok(history)
+    }
+
+    /// Pick a parent index from a population sorted best-first
+    fn select_parent(&self, sorted: &[Genome<Rot180>], strategy: SelectionStrategy) -> usize {
+        let mut rng = self.rng.lock();
+        let n = sorted.len();
+
+        match strategy {
+            SelectionStrategy::Tournament => {
+                let a = rng.gen_range(0..n);
+                let b = rng.gen_range(0..n);
+                let (fitter, weaker) = if a <= b { (a, b) } else { (b, a) };
+                if rng.gen::<f64>() < self.selection_pressure { fitter } else { weaker }
+            }
+            SelectionStrategy::Rank => {
+                // Worst rank gets weight 2 - SP, best gets SP
+                let sp = 1.0 + self.selection_pressure;
+                let weight = |i: usize| {
+                    let rank = (n - 1 - i) as f64 / (n - 1) as f64;
+                    2.0 - sp + 2.0 * (sp - 1.0) * rank
+                };
+                let total: f64 = (0..n).map(weight).sum();
+                let mut roll = rng.gen::<f64>() * total;
+                for i in 0..n {
+                    roll -= weight(i);
+                    if roll <= 0.0 {
+                        return i;
+                    }
+                }
+                n - 1
+            }
+        }
+    }
+
+    /// Independent per-tetrad point mutation at `mutation_rate`
+    /// Per-tetrad mutation; diploid genomes mutate each homolog and re-express
+    fn mutate_tetrads(&self, genome: &mut Genome<Rot180>) -> u64 {
+        let mut rng = self.rng.lock();
+        match &mut genome.homologs {
+            Some(homologs) => {
+                let count = self.mutate_strand(&mut homologs.maternal, &mut *rng)
+                    + self.mutate_strand(&mut homologs.paternal, &mut *rng);
+                genome.data = homologs.express();
+                count
+            }
+            None => self.mutate_strand(&mut genome.data, &mut *rng),
+        }
+    }
+
+    fn mutate_strand<G: Rng + ?Sized>(&self, strand: &mut [Tetrad; GENOME_SIZE], rng: &mut G) -> u64 {
+        let mut count = 0;
+        for tetrad in strand.iter_mut() {
+            if rng.gen::<f64>() < self.mutation_rate {
+                // One of the three other bases, so every counted mutation changes the tetrad
+                *tetrad = Tetrad::from_u8(*tetrad as u8 + rng.gen_range(1..4));
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn generation_stats(
+        generation: u32,
+        population: &[Genome<Rot180>],
+        elites_kept: usize,
+        offspring_stored: usize,
+        tetrad_mutations: u64,
+    ) -> GenerationStats {
+        let scores: Vec<u32> = population.iter().map(|g| g.normalized_consciousness()).collect();
+        let best = population.iter().max_by_key(|g| g.normalized_consciousness());
+        let unique: std::collections::HashSet<String> = population.iter().map(|g| g.to_dna_string()).collect();
+
+        GenerationStats {
+            generation,
+            population: population.len(),
+            best_consciousness: scores.iter().copied().max().unwrap_or(0),
+            mean_consciousness: scores.iter().map(|&c| c as f64).sum::<f64>() / scores.len().max(1) as f64,
+            worst_consciousness: scores.iter().copied().min().unwrap_or(0),
+            best_genome_id: best.and_then(|g| g.db_id),
+            unique_dna: unique.len(),
+            elites_kept,
+            offspring_stored,
+            tetrad_mutations,
+        }
+    }
 }
 
 impl Default for TTRLEngine {